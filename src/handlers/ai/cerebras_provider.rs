@@ -43,7 +43,7 @@ impl CerebrasProvider {
         let client = reqwest::Client::new();
 
         let model = match model {
-            None => "gpt-oss-120b",
+            None => DEFAULT_MODEL,
             Some(CliModel::Llama31_70B) => "llama3.1-70b",
             Some(CliModel::Llama31_8B) => "llama3.1-8b",
 
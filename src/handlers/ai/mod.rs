@@ -2,10 +2,13 @@ mod cerebras_provider;
 mod gemini_provider;
 
 use crate::models::{cli::CliModel, error::APIError};
+use tokio_retry::{Retry, strategy::FixedInterval};
 
 pub use cerebras_provider::CerebrasProvider;
 pub use gemini_provider::GeminiProvider;
 
+const ATTEMPTS: usize = 3; // TODO: Add custom attempts
+
 #[async_trait::async_trait]
 pub trait AIProvider: Send + Sync {
     async fn generate_content(
@@ -32,3 +35,18 @@ pub fn create_provider(
         }
     }
 }
+
+/// Sends `parts` to the provider with the system `prompt`, retrying failed requests, and
+/// reports the last failure under `context`.
+pub async fn generate_with_retry(
+    provider: &dyn AIProvider,
+    prompt: &str,
+    parts: &[&str],
+    context: &str,
+) -> Result<String, APIError> {
+    Retry::spawn(FixedInterval::from_millis(100).take(ATTEMPTS), || {
+        provider.generate_content(Some(prompt), parts.to_vec())
+    })
+    .await
+    .map_err(|e| APIError::new(context, e))
+}
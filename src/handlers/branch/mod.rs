@@ -0,0 +1,166 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        commit::{diff::get_git_diff, filter::filter_diff, refs::first_reference},
+        git::{self, git_cmd},
+        json,
+    },
+    models::{
+        branch::BranchSuggestion,
+        cli::{CliModel, CommitVariant, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+
+const TICKET: &str = "{ticket}";
+
+/// Lowercases text and joins its alphanumeric words with dashes.
+fn kebab_case(text: &str) -> String {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Drops the `{ticket}` placeholder from a pattern along with the separator after it, or
+/// before it when it ends the pattern.
+fn without_ticket(pattern: &str) -> String {
+    let Some(at) = pattern.find(TICKET) else {
+        return pattern.to_string();
+    };
+    let is_separator = |c: char| matches!(c, '-' | '_' | '/' | '.');
+    let (before, after) = (&pattern[..at], &pattern[at + TICKET.len()..]);
+
+    match after.strip_prefix(is_separator) {
+        Some(after) => format!("{}{}", before, after),
+        None => format!(
+            "{}{}",
+            before.strip_suffix(is_separator).unwrap_or(before),
+            after
+        ),
+    }
+}
+
+/// Fills the `{type}`, `{slug}` and `{ticket}` placeholders of a branch pattern. Without a
+/// ticket, the `{ticket}` placeholder and its separator are left out.
+fn apply_pattern(pattern: &str, suggestion: &BranchSuggestion, ticket: Option<&str>) -> String {
+    let pattern = match ticket {
+        Some(ticket) => pattern.replace(TICKET, ticket),
+        None => without_ticket(pattern),
+    };
+
+    pattern
+        .replace("{type}", &kebab_case(&suggestion.branch_type))
+        .replace("{slug}", &kebab_case(&suggestion.slug))
+}
+
+fn is_valid_branch_name(name: &str) -> bool {
+    git_cmd(
+        &["check-ref-format", "--branch", name],
+        "git check-ref-format",
+    )
+    .is_ok()
+}
+
+/// Suggests branch names and switches to the chosen one. `{ticket}` in the pattern is
+/// filled from a ticket key or issue id in the description, found with `ref_pattern`.
+pub async fn handle_branch(
+    description: Option<&str>,
+    pattern: &str,
+    ref_pattern: &str,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("BRANCH NAME");
+    ui::Logger::kv("Current branch", &git::current_branch()?);
+
+    let (user_message, description) = match description {
+        Some(description) => (
+            prompts::BRANCH_DESCRIPTION_PROMPT.replace("{}", description),
+            Some(description.to_string()),
+        ),
+        None => match get_git_diff(Some(CommitVariant::Any)) {
+            Ok(diff) => (
+                prompts::BRANCH_DIFF_PROMPT.replace("{}", &filter_diff(&diff)),
+                None,
+            ),
+            Err(_) => {
+                ui::Logger::warning("No changes found to name the branch after.");
+                let description = ui::Input::text("Describe the work in a few words:");
+                (
+                    prompts::BRANCH_DESCRIPTION_PROMPT.replace("{}", &description),
+                    Some(description),
+                )
+            }
+        },
+    };
+
+    let ticket = match &description {
+        Some(description) if pattern.contains(TICKET) => first_reference(description, ref_pattern)?,
+        _ => None,
+    };
+    if let Some(ticket) = &ticket {
+        ui::Logger::kv("Ticket", ticket);
+    }
+
+    let mut loader = InfiniteLoader::new("Suggesting branch names");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::BRANCH_PROMPT,
+        &[&user_message],
+        "AI provider branch naming",
+    )
+    .await?;
+
+    let json_str = json::handle_json_strip(&response);
+    let suggestions: Vec<BranchSuggestion> =
+        serde_json::from_str(&json_str).map_err(|e| APIError::new("Invalid branch JSON", e))?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Branch names done");
+    eprintln!();
+
+    let mut names: Vec<String> = Vec::new();
+    for suggestion in &suggestions {
+        let name = apply_pattern(pattern, suggestion, ticket.as_deref());
+        if !names.contains(&name) && is_valid_branch_name(&name) {
+            names.push(name);
+        }
+    }
+
+    if names.is_empty() {
+        return Err(APIError::new_msg(
+            "Branch name",
+            &format!(
+                "No valid branch names could be built from the pattern '{}'",
+                pattern
+            ),
+        ));
+    }
+
+    let mut options: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+    options.push("Cancel");
+
+    let selected_idx = ui::Input::select("Which branch should be created?", &options);
+    if selected_idx == names.len() {
+        ui::Logger::warning("No branch was created.");
+        return Ok(());
+    }
+
+    let name = &names[selected_idx];
+    ui::Logger::dim(&format!("Executing git switch -c {}...", name));
+    git_cmd(&["switch", "-c", name], "git switch -c")?;
+    ui::Logger::success(&format!("Switched to a new branch {}", name));
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use crate::{
+    handlers::{ai, changelog::prompts, git::LogCommit, json},
+    models::{
+        changelog::{ChangeCategory, ChangelogEntry, CommitClassification},
+        error::APIError,
+        ui,
+    },
+};
+use regex::Regex;
+
+// Longest part of a commit body sent to the model when classifying it.
+const MAX_BODY_HINT_LENGTH: usize = 200;
+
+fn type_category(commit_type: &str) -> Option<ChangeCategory> {
+    match commit_type {
+        "feat" | "feature" => Some(ChangeCategory::Added),
+        "fix" | "bugfix" | "hotfix" => Some(ChangeCategory::Fixed),
+        "perf" | "refactor" => Some(ChangeCategory::Changed),
+        "revert" | "remove" => Some(ChangeCategory::Removed),
+        "deprecate" => Some(ChangeCategory::Deprecated),
+        "security" | "sec" => Some(ChangeCategory::Security),
+        "docs" | "style" | "test" | "chore" | "build" | "ci" => Some(ChangeCategory::Ignored),
+        _ => None,
+    }
+}
+
+/// Maps the emoji prefixes used by `COMMIT_PROMPT_WITH_EMOJI` to a section.
+fn emoji_category(emoji: &str) -> Option<ChangeCategory> {
+    match emoji.trim_end_matches('\u{fe0f}') {
+        "✨" | "🎉" => Some(ChangeCategory::Added),
+        "🐛" => Some(ChangeCategory::Fixed),
+        "🔒" => Some(ChangeCategory::Security),
+        "🔥" => Some(ChangeCategory::Removed),
+        "⚡" | "♻" | "🎨" => Some(ChangeCategory::Changed),
+        "📝" | "💄" | "🔧" | "📦" | "⬆" | "⬇" | "🚀" | "🚧" => {
+            Some(ChangeCategory::Ignored)
+        }
+        _ => None,
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Classifies a commit written as `[emoji] type(scope)!: description` or `emoji description`.
+/// Returns `None` when the subject follows neither convention.
+fn conventional_entry(pattern: &Regex, commit: &LogCommit) -> Option<ChangelogEntry> {
+    let caps = pattern.captures(&commit.subject)?;
+    let description = caps.name("desc")?.as_str().trim();
+
+    let category = match caps.name("type") {
+        Some(commit_type) => type_category(&commit_type.as_str().to_lowercase())?,
+        None => emoji_category(caps.name("emoji")?.as_str())?,
+    };
+
+    let breaking = caps.name("breaking").is_some() || commit.body.contains("BREAKING CHANGE");
+    let mut text = capitalize(description);
+    if let Some(scope) = caps.name("scope").filter(|s| !s.as_str().is_empty()) {
+        text = format!("**{}**: {}", scope.as_str(), text);
+    }
+    if breaking {
+        text = format!("**BREAKING**: {}", text);
+    }
+
+    Some(ChangelogEntry {
+        category,
+        description: text,
+        sha: commit.sha.clone(),
+    })
+}
+
+async fn classify_with_ai(
+    ai_provider: &dyn ai::AIProvider,
+    commits: &[&LogCommit],
+) -> Result<Vec<CommitClassification>, APIError> {
+    let listing = commits
+        .iter()
+        .enumerate()
+        .map(|(i, commit)| {
+            let body: String = commit
+                .body
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .chars()
+                .take(MAX_BODY_HINT_LENGTH)
+                .collect();
+            if body.is_empty() {
+                format!("{}: {}", i, commit.subject)
+            } else {
+                format!("{}: {} | {}", i, commit.subject, body)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let user_message = prompts::CHANGELOG_CLASSIFY_USER_PROMPT.replace("{}", &listing);
+
+    let response = ai::generate_with_retry(
+        ai_provider,
+        prompts::CHANGELOG_CLASSIFY_PROMPT,
+        &[&user_message],
+        "AI provider changelog classification",
+    )
+    .await?;
+
+    let json_str = json::handle_json_strip(&response);
+
+    serde_json::from_str(&json_str).map_err(|e| APIError::new("Invalid classification JSON", e))
+}
+
+/// Sorts commits into changelog entries. Conventional and emoji-prefixed subjects are
+/// classified locally; the rest are classified by the AI provider, which is only created
+/// when such commits exist.
+pub async fn classify_commits(
+    commits: &[LogCommit],
+    provider: crate::models::cli::Provider,
+    model: Option<crate::models::cli::CliModel>,
+) -> Result<Vec<ChangelogEntry>, APIError> {
+    let pattern = Regex::new(
+        r"^(?:(?P<emoji>[^\w\s(]+)\s*)?(?:(?P<type>[A-Za-z]+)(?:\((?P<scope>[^)]*)\))?(?P<breaking>!)?:\s*)?(?P<desc>.+)$",
+    )
+    .map_err(|e| APIError::new("Changelog pattern", e))?;
+
+    let mut entries = Vec::new();
+    let mut unclassified = Vec::new();
+
+    for (position, commit) in commits.iter().enumerate() {
+        match conventional_entry(&pattern, commit) {
+            Some(entry) => entries.push((position, entry)),
+            None => unclassified.push((position, commit)),
+        }
+    }
+
+    if !unclassified.is_empty() {
+        ui::Logger::step(&format!(
+            "Classifying {} non-conventional commit(s) with AI...",
+            unclassified.len()
+        ));
+
+        let ai_provider = ai::create_provider(provider, model)?;
+        let pending: Vec<&LogCommit> = unclassified.iter().map(|(_, commit)| *commit).collect();
+        let classifications = classify_with_ai(ai_provider.as_ref(), &pending).await?;
+
+        for (i, (position, commit)) in unclassified.iter().enumerate() {
+            let classification = classifications.iter().find(|c| c.index == i);
+            let category = classification.map_or(ChangeCategory::Changed, |c| c.category);
+            let description = classification
+                .and_then(|c| c.summary.clone())
+                .filter(|s| !s.trim().is_empty())
+                .unwrap_or_else(|| capitalize(&commit.subject));
+
+            entries.push((
+                *position,
+                ChangelogEntry {
+                    category,
+                    description,
+                    sha: commit.sha.clone(),
+                },
+            ));
+        }
+    }
+
+    // Keep the `git log` order inside each section.
+    entries.sort_by_key(|(position, _)| *position);
+
+    Ok(entries.into_iter().map(|(_, entry)| entry).collect())
+}
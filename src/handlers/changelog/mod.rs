@@ -0,0 +1,139 @@
+mod classify;
+mod prompts;
+mod writer;
+
+use crate::{
+    handlers::git::{self, git_cmd},
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui,
+    },
+};
+
+const DEFAULT_CHANGELOG_PATH: &str = "CHANGELOG.md";
+
+/// A release range between two tags. `from` is `None` for the first release.
+pub struct TagRange {
+    pub from: Option<String>,
+    pub to: String,
+}
+
+impl TagRange {
+    /// The revision range to pass to `git log`.
+    pub fn log_range(&self) -> String {
+        match &self.from {
+            Some(from) => format!("{}..{}", from, self.to),
+            None => self.to.clone(),
+        }
+    }
+
+    /// Version label for the release, `Unreleased` when the range ends at `HEAD`.
+    pub fn label(&self) -> String {
+        if self.to == "HEAD" {
+            "Unreleased".to_string()
+        } else {
+            self.to.strip_prefix('v').unwrap_or(&self.to).to_string()
+        }
+    }
+}
+
+fn previous_tag(rev: &str) -> Option<String> {
+    git_cmd(
+        &["describe", "--tags", "--abbrev=0", rev],
+        "git describe --tags",
+    )
+    .ok()
+}
+
+/// Resolves `from..to`, a single tag (compared with the tag before it), or nothing
+/// (changes since the latest tag).
+pub fn resolve_tag_range(target: Option<&str>) -> Result<TagRange, APIError> {
+    let range = match target {
+        None => TagRange {
+            from: previous_tag("HEAD"),
+            to: "HEAD".to_string(),
+        },
+        Some(target) => match target.split_once("..") {
+            Some((from, to)) => TagRange {
+                from: (!from.is_empty()).then(|| from.to_string()),
+                to: if to.is_empty() { "HEAD" } else { to }.to_string(),
+            },
+            None => TagRange {
+                from: previous_tag(&format!("{}^", target)),
+                to: target.to_string(),
+            },
+        },
+    };
+
+    for rev in range.from.iter().chain(std::iter::once(&range.to)) {
+        git_cmd(
+            &["rev-parse", "--verify", rev],
+            &format!("rev-parse {}", rev),
+        )?;
+    }
+
+    Ok(range)
+}
+
+fn link_reference(range: &TagRange, web_url: &str) -> String {
+    match &range.from {
+        Some(from) => format!(
+            "[{}]: {}/compare/{}...{}",
+            range.label(),
+            web_url,
+            from,
+            range.to
+        ),
+        None => format!("[{}]: {}/releases/tag/{}", range.label(), web_url, range.to),
+    }
+}
+
+pub async fn handle_changelog(
+    target: Option<&str>,
+    output: Option<&str>,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("CHANGELOG GENERATOR");
+
+    let range = resolve_tag_range(target)?;
+    ui::Logger::step(&format!("Reading commits in {}...", range.log_range()));
+
+    let commits = git::log_commits(&range.log_range())?;
+    if commits.is_empty() {
+        return Err(APIError::new_msg(
+            "Changelog",
+            &format!("No commits found in {}", range.log_range()),
+        ));
+    }
+    ui::Logger::dim(&format!("Found {} commit(s).", commits.len()));
+
+    let entries = classify::classify_commits(&commits, provider, model).await?;
+
+    let web_url = git::origin_url()
+        .ok()
+        .and_then(|origin| git::remote_web_url(&origin));
+    let date = if range.to == "HEAD" {
+        None
+    } else {
+        Some(git_cmd(
+            &["log", "-1", "--format=%as", &range.to],
+            "git log --format=%as",
+        )?)
+    };
+
+    let label = range.label();
+    let section = writer::render_section(&label, date.as_deref(), &entries, web_url.as_deref());
+    let reference = web_url.as_deref().map(|url| link_reference(&range, url));
+
+    let file_path = output.unwrap_or(DEFAULT_CHANGELOG_PATH);
+    ui::Logger::step(&format!("Writing {}...", file_path));
+    writer::write_changelog(file_path, &label, &section, reference.as_deref())?;
+
+    ui::Logger::done("Changelog successfully generated!");
+    ui::Logger::kv("Location", file_path);
+    ui::Logger::kv("Section", &label);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+pub const CHANGELOG_CLASSIFY_PROMPT: &str = r#"You are an assistant that sorts Git commits into Keep a Changelog sections.
+
+CATEGORIES:
+- Added: new features or capabilities
+- Changed: changes to existing behaviour, performance work, user-visible refactors
+- Deprecated: features that will be removed soon
+- Removed: features or APIs that were removed
+- Fixed: bug fixes
+- Security: vulnerability fixes or security hardening
+- Ignored: changes that do not matter to users (CI, formatting, internal chores, merges, version bumps)
+
+For every commit, also write "summary": a short, user-facing changelog line in the imperative mood, without a trailing period and without a conventional commit prefix.
+
+OUTPUT FORMAT (STRICT JSON ARRAY):
+[
+  { "index": 0, "category": "Fixed", "summary": "Fix crash when the config file is empty" }
+]
+
+CRITICAL RULES:
+1. Return ONLY valid JSON, no markdown fences or explanations
+2. Include every commit index exactly once
+3. "category" must be one of: Added, Changed, Deprecated, Removed, Fixed, Security, Ignored
+"#;
+
+pub const CHANGELOG_CLASSIFY_USER_PROMPT: &str = r#"Classify these commits. Each line starts with the commit index, followed by its subject and the start of its body:
+
+{}"#;
@@ -0,0 +1,171 @@
+use crate::models::{
+    changelog::{ChangeCategory, ChangelogEntry},
+    error::APIError,
+};
+use std::{fs, path::Path};
+
+const CHANGELOG_HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+";
+
+/// Renders one Keep a Changelog release section, linking commits when the web URL is known.
+pub fn render_section(
+    label: &str,
+    date: Option<&str>,
+    entries: &[ChangelogEntry],
+    web_url: Option<&str>,
+) -> String {
+    let mut section = match date {
+        Some(date) => format!("## [{}] - {}\n", label, date),
+        None => format!("## [{}]\n", label),
+    };
+
+    for category in ChangeCategory::SECTIONS {
+        let lines: Vec<String> = entries
+            .iter()
+            .filter(|entry| entry.category == category)
+            .map(|entry| {
+                let short_sha = &entry.sha[..7.min(entry.sha.len())];
+                match web_url {
+                    Some(url) => format!(
+                        "- {} ([{}]({}/commit/{}))",
+                        entry.description, short_sha, url, entry.sha
+                    ),
+                    None => format!("- {} ({})", entry.description, short_sha),
+                }
+            })
+            .collect();
+
+        if !lines.is_empty() {
+            section.push_str(&format!(
+                "\n### {}\n\n{}\n",
+                category.title(),
+                lines.join("\n")
+            ));
+        }
+    }
+
+    section
+}
+
+fn is_link_reference(line: &str) -> bool {
+    line.starts_with('[') && line.contains("]: ")
+}
+
+/// Inserts a release section above the existing releases of a changelog (creating the file
+/// when needed) and adds its link reference above the existing ones. An existing
+/// `[Unreleased]` section and its link reference are replaced.
+pub fn write_changelog(
+    path: &str,
+    label: &str,
+    section: &str,
+    link_reference: Option<&str>,
+) -> Result<(), APIError> {
+    let existing = if Path::new(path).exists() {
+        fs::read_to_string(path).map_err(|e| APIError::new("fs::read_to_string", e))?
+    } else {
+        CHANGELOG_HEADER.to_string()
+    };
+
+    let mut lines: Vec<String> = existing.lines().map(String::from).collect();
+
+    let heading = format!("## [{}]", label);
+    if let Some(start) = lines.iter().position(|line| line.starts_with(&heading)) {
+        if label != "Unreleased" {
+            return Err(APIError::new_msg(
+                "Changelog",
+                &format!("{} already has a section for [{}]", path, label),
+            ));
+        }
+
+        // The unreleased section is regenerated on every run, so the old one is replaced.
+        let end = lines[start + 1..]
+            .iter()
+            .position(|line| line.starts_with("## ") || is_link_reference(line))
+            .map_or(lines.len(), |at| start + 1 + at);
+        lines.drain(start..end);
+        let old_reference = format!("[{}]: ", label);
+        lines.retain(|line| !line.starts_with(&old_reference));
+    }
+
+    let section_at = lines
+        .iter()
+        .position(|line| line.starts_with("## "))
+        .unwrap_or(lines.len());
+    let mut block: Vec<String> = section.trim_end().lines().map(String::from).collect();
+    block.push(String::new());
+    if section_at == lines.len() && lines.last().is_some_and(|line| !line.is_empty()) {
+        block.insert(0, String::new());
+    }
+    lines.splice(section_at..section_at, block);
+
+    if let Some(reference) = link_reference {
+        let reference_at = lines.iter().position(|line| is_link_reference(line));
+        match reference_at {
+            Some(at) => lines.insert(at, reference.to_string()),
+            None => {
+                if lines.last().is_some_and(|line| !line.is_empty()) {
+                    lines.push(String::new());
+                }
+                lines.push(reference.to_string());
+            }
+        }
+    }
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+
+    fs::write(path, content).map_err(|e| APIError::new("fs::write", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(category: ChangeCategory, description: &str) -> ChangelogEntry {
+        ChangelogEntry {
+            category,
+            description: description.to_string(),
+            sha: "0123456789abcdef".to_string(),
+        }
+    }
+
+    #[test]
+    fn replaces_unreleased_and_adds_releases_on_top() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("CHANGELOG.md");
+        let path = path.to_str().unwrap();
+        let url = "https://example.com/repo";
+
+        let unreleased = render_section(
+            "Unreleased",
+            None,
+            &[entry(ChangeCategory::Fixed, "Fix a crash")],
+            Some(url),
+        );
+        write_changelog(path, "Unreleased", &unreleased, Some("[Unreleased]: a")).unwrap();
+        let unreleased = render_section(
+            "Unreleased",
+            None,
+            &[entry(ChangeCategory::Added, "Add export")],
+            None,
+        );
+        write_changelog(path, "Unreleased", &unreleased, Some("[Unreleased]: b")).unwrap();
+
+        let release = render_section("1.0.0", Some("2026-01-01"), &[], None);
+        write_changelog(path, "1.0.0", &release, Some("[1.0.0]: c")).unwrap();
+        assert!(write_changelog(path, "1.0.0", &release, None).is_err());
+
+        let content = fs::read_to_string(path).unwrap();
+        assert!(content.starts_with(CHANGELOG_HEADER));
+        assert!(!content.contains("Fix a crash"));
+        assert!(content.contains("### Added\n\n- Add export (0123456)\n"));
+        let release_at = content.find("## [1.0.0] - 2026-01-01").unwrap();
+        assert!(release_at < content.find("## [Unreleased]").unwrap());
+        assert!(content.ends_with("[1.0.0]: c\n[Unreleased]: b\n"));
+    }
+}
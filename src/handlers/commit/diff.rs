@@ -3,16 +3,11 @@ use crate::{
     models::{self, error::APIError, ui},
 };
 
-pub fn get_git_diff(commit_scope: Option<models::cli::CommitVariant>) -> Result<String, APIError> {
+fn extract_diff(target: &[&str], scope_display: &str) -> Result<String, APIError> {
     let mut loader = ui::InfiniteLoader::new("Extracting the git diff");
 
     loader.tick();
 
-    let scope = &commit_scope
-        .clone()
-        .unwrap_or(models::cli::CommitVariant::Any);
-    loader.tick();
-
     let mut command = vec![
         "diff",
         "--no-color",
@@ -20,38 +15,22 @@ pub fn get_git_diff(commit_scope: Option<models::cli::CommitVariant>) -> Result<
         "--minimal",
         "--unified=3",
     ];
-
-    match scope {
-        models::cli::CommitVariant::Staged => {
-            command.push("--staged");
-        }
-        models::cli::CommitVariant::Any => {
-            command.push("HEAD");
-        }
-    }
+    command.extend_from_slice(target);
     loader.tick();
 
     let diff = handlers::git::git_cmd(&command, "Git diff extraction").map_err(|_| {
         ui::Logger::clear_line();
 
-        let scope_display = commit_scope
-            .as_ref()
-            .map_or(&models::cli::CommitVariant::Any, |v| v);
-
-        return APIError::new_msg(
+        APIError::new_msg(
             "Git diff extraction",
             &format!(
                 "Failed to extract {} diff. Check if there are any differences.",
                 scope_display
             ),
-        );
+        )
     })?;
 
     if diff.trim() == "" {
-        let scope_display = commit_scope
-            .as_ref()
-            .map_or(&models::cli::CommitVariant::Any, |v| v);
-        
         return Err(APIError::new_msg(
             "Git diff extraction",
             &format!(
@@ -65,4 +44,59 @@ pub fn get_git_diff(commit_scope: Option<models::cli::CommitVariant>) -> Result<
     loader.finish("Extracted the git diff from the system!");
 
     Ok(diff)
-}
\ No newline at end of file
+}
+
+fn scope_target(scope: &models::cli::CommitVariant) -> &'static [&'static str] {
+    match scope {
+        models::cli::CommitVariant::Staged => &["--staged"],
+        models::cli::CommitVariant::Any => &["HEAD"],
+    }
+}
+
+pub fn get_git_diff(commit_scope: Option<models::cli::CommitVariant>) -> Result<String, APIError> {
+    let scope = commit_scope.unwrap_or(models::cli::CommitVariant::Any);
+
+    extract_diff(scope_target(&scope), &scope.to_string())
+}
+
+/// Whether the scope has no changes at all, as opposed to a diff that cannot be read.
+pub fn is_diff_empty(commit_scope: Option<&models::cli::CommitVariant>) -> bool {
+    let scope = commit_scope.unwrap_or(&models::cli::CommitVariant::Any);
+
+    let mut command = vec!["diff", "--name-only"];
+    command.extend_from_slice(scope_target(scope));
+    handlers::git::git_cmd(&command, "git diff --name-only").is_ok_and(|files| files.is_empty())
+}
+
+/// Extracts the diff an amended `HEAD` would contain: the changes of the last commit
+/// (`HEAD~1..HEAD`) together with anything currently staged.
+pub fn get_amend_diff() -> Result<String, APIError> {
+    let base = handlers::git::git_cmd(
+        &["rev-parse", "--verify", "--quiet", "HEAD~1"],
+        "rev-parse HEAD~1",
+    )
+    .unwrap_or_else(|_| handlers::git::EMPTY_TREE.to_string());
+
+    extract_diff(&["--staged", &base], "amend")
+}
+
+/// Extracts the changes introduced by a single commit, including root commits.
+pub fn get_commit_diff(rev: &str) -> Result<String, APIError> {
+    handlers::git::git_cmd(
+        &[
+            "show",
+            "--format=",
+            "--no-color",
+            "--no-ext-diff",
+            "--minimal",
+            "--unified=3",
+            rev,
+        ],
+        &format!("git show {}", rev),
+    )
+}
+
+/// Extracts the diff for a revision range such as `main...HEAD`.
+pub fn get_range_diff(range: &str) -> Result<String, APIError> {
+    extract_diff(&[range], range)
+}
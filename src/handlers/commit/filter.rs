@@ -27,26 +27,26 @@ pub fn filter_diff(diff: &str) -> String {
 
     for part in diff.split("diff --git ").skip(1) {
         let mut file_processed = false;
-        if let Some(first_line) = part.lines().next() {
-            if let Some(file_path_a) = first_line.split_whitespace().next() {
-                let file_name = file_path_a.strip_prefix("a/").unwrap_or(file_path_a);
-                if is_ignorable_file(file_name) {
-                    continue; // Skip ignorable files completely
-                }
-                file_processed = true;
+        if let Some(first_line) = part.lines().next()
+            && let Some(file_path_a) = first_line.split_whitespace().next()
+        {
+            let file_name = file_path_a.strip_prefix("a/").unwrap_or(file_path_a);
+            if is_ignorable_file(file_name) {
+                continue; // Skip ignorable files completely
+            }
+            file_processed = true;
 
-                let status = match part {
-                    _ if part.contains("new file mode ") => "added",
-                    _ if part.contains("deleted file mode ") => "deleted",
-                    _ => "modified",
-                };
-                changed_files_summary.push(format!("- {} ({})", file_name, status));
+            let status = match part {
+                _ if part.contains("new file mode ") => "added",
+                _ if part.contains("deleted file mode ") => "deleted",
+                _ => "modified",
+            };
+            changed_files_summary.push(format!("- {} ({})", file_name, status));
 
-                if is_config_file(file_name) {
-                    priority_parts.push(format!("diff --git {}", part));
-                } else {
-                    other_parts.push(format!("diff --git {}", part));
-                }
+            if is_config_file(file_name) {
+                priority_parts.push(format!("diff --git {}", part));
+            } else {
+                other_parts.push(format!("diff --git {}", part));
             }
         }
         if !file_processed {
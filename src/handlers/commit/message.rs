@@ -1,7 +1,11 @@
 use crate::{
     handlers::{
         self, ai,
-        commit::{filter, prompts},
+        commit::{
+            filter, prompts,
+            refs::{self, RefsConfig},
+            trailers::{self, TrailersConfig},
+        },
     },
     models::{
         self,
@@ -10,7 +14,6 @@ use crate::{
         ui::{self, InfiniteLoader},
     },
 };
-use tokio_retry::{Retry, strategy::FixedInterval};
 
 fn clean_commit_message(message: &str) -> String {
     let mut cleaned_message = message.trim().to_string();
@@ -69,22 +72,81 @@ fn clean_commit_message(message: &str) -> String {
     cleaned_message
 }
 
-pub async fn handle_commit_message(
-    commit_scope: Option<models::cli::CommitVariant>,
+/// Options for generating a commit message for the working tree or the last commit.
+pub struct CommitOptions {
+    pub commit_scope: Option<models::cli::CommitVariant>,
+    pub no_emoji: bool,
+    /// Describe `HEAD~1..HEAD` plus staged changes, for `git commit --amend`.
+    pub amend: bool,
+    /// Pass the current `HEAD` message to the model as a hint when amending.
+    pub hint_previous: bool,
+    pub refs: Option<RefsConfig>,
+    pub trailers: TrailersConfig,
+}
+
+/// Asks the provider for a commit message describing `diff` and cleans the response.
+///
+/// `context` holds extra user messages sent after the diff, such as an existing message
+/// the model may use to understand the intent of the change.
+pub async fn generate_commit_message(
+    ai_provider: &dyn ai::AIProvider,
+    diff: &str,
     no_emoji: bool,
+    context: &[String],
+) -> Result<String, APIError> {
+    let filtered_contents = filter::filter_diff(diff);
+
+    let system_prompt = if no_emoji {
+        prompts::COMMIT_PROMPT_NO_EMOJI
+    } else {
+        prompts::COMMIT_PROMPT_WITH_EMOJI
+    };
+
+    let mut user_messages =
+        vec![prompts::COMMIT_USER_MESSAGE_PROMPT.replace("{}", &filtered_contents)];
+    user_messages.extend_from_slice(context);
+
+    let message = ai::generate_with_retry(
+        ai_provider,
+        system_prompt,
+        &user_messages.iter().map(String::as_str).collect::<Vec<_>>(),
+        "AI provider commit message generation",
+    )
+    .await?;
+
+    Ok(clean_commit_message(&message))
+}
+
+pub async fn handle_commit_message(
+    options: CommitOptions,
     provider: models::cli::Provider,
     model: Option<CliModel>,
 ) -> Result<String, APIError> {
-    ui::Logger::dim(&format!(
-        "Starting execution of creating a {} commit",
-        commit_scope
-            .as_ref()
-            .map_or(&models::cli::CommitVariant::Any, |v| v)
-    ));
-
-    let diff = handlers::commit::diff::get_git_diff(commit_scope)?;
+    let (diff, context) = if options.amend {
+        ui::Logger::dim("Starting execution of amending the last commit");
+
+        let mut context = Vec::new();
+        if options.hint_previous {
+            let previous =
+                handlers::git::git_cmd(&["log", "-1", "--pretty=%B"], "log -1 --pretty=%B")?;
+            context.push(prompts::COMMIT_HINT_PROMPT.replace("{}", &previous));
+        }
 
-    let filtered_contents = filter::filter_diff(&diff);
+        (handlers::commit::diff::get_amend_diff()?, context)
+    } else {
+        ui::Logger::dim(&format!(
+            "Starting execution of creating a {} commit",
+            options
+                .commit_scope
+                .as_ref()
+                .map_or(&models::cli::CommitVariant::Any, |v| v)
+        ));
+
+        (
+            handlers::commit::diff::get_git_diff(options.commit_scope)?,
+            Vec::new(),
+        )
+    };
 
     let mut loader = InfiniteLoader::new("Ai Agent initialization.");
 
@@ -97,33 +159,21 @@ pub async fn handle_commit_message(
     loader.set_progress(45.0);
     loader.tick();
 
-    let system_prompt = if no_emoji {
-        prompts::COMMIT_PROMPT_NO_EMOJI
-    } else {
-        prompts::COMMIT_PROMPT_WITH_EMOJI
-    };
-
-    let attempts = 3; // TODO: Add custom attempts
-
-    let message = Retry::spawn(FixedInterval::from_millis(100).take(attempts), || async {
-        ai_provider
-            .generate_content(
-                Some(system_prompt),
-                vec![&prompts::COMMIT_USER_MESSAGE_PROMPT.replace("{}", &filtered_contents)],
-            )
-            .await
-    })
-    .await
-    .map_err(|e| APIError::new("AI provider commit message generation", e))?;
-
-    let cleaned_message = clean_commit_message(&message);
+    let mut cleaned_message =
+        generate_commit_message(ai_provider.as_ref(), &diff, options.no_emoji, &context).await?;
 
     loader.set_progress(100.0);
     loader.tick();
 
     loader.finish("Commit message done");
-    println!();
-    ui::Logger::command(&cleaned_message);
+
+    if let Some(config) = &options.refs {
+        cleaned_message = refs::append_reference_footers(&cleaned_message, config)?;
+    }
+    cleaned_message = trailers::append_trailers(&cleaned_message, &options.trailers)?;
+
+    eprintln!();
+    println!("{}", cleaned_message);
 
     Ok(cleaned_message)
 }
@@ -2,3 +2,5 @@ pub mod diff;
 pub mod filter;
 pub mod message;
 pub mod prompts;
+pub mod refs;
+pub mod trailers;
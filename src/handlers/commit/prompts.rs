@@ -67,3 +67,15 @@ IMPORTANT: Output ONLY the commit message itself. Do NOT include:
 - Meta-commentary or descriptions
 - Code blocks or markdown formatting around the message
 Start directly with the commit message (e.g., 'fix(scope): description' or '✨ fix(scope): description')."#;
+
+pub const COMMIT_HINT_PROMPT: &str = r#"The commit is currently described by the message below. Use it only as a hint about the intent of the change; the new message must describe the full diff above:
+
+```
+{}
+```"#;
+
+pub const SQUASH_LOG_PROMPT: &str = r#"The diff above squashes the commits listed below (oldest first) into a single commit. Write ONE coherent commit message for the combined change: summarise the overall purpose in the subject and cover the notable changes in the body. Do not list every commit, and ignore noise such as 'wip', 'fix typo' or review follow-ups:
+
+```
+{}
+```"#;
@@ -0,0 +1,155 @@
+use crate::{
+    handlers::git,
+    models::{error::APIError, ui},
+};
+use regex::{Captures, Regex};
+
+/// Settings for turning the current branch name into commit message footers.
+pub struct RefsConfig {
+    /// Regex matched against the branch name. A `ticket` or `issue` named group picks the
+    /// footer format; otherwise the first group (or the whole match) is used as a ticket.
+    pub pattern: String,
+    /// Footer format for ticket keys such as `PROJ-1234`. `{}` is replaced by the key.
+    pub ticket_footer: String,
+    /// Footer format for numeric issue ids such as `42`. `{}` is replaced by the id.
+    pub issue_footer: String,
+}
+
+/// The issue id or ticket key of one match, and whether it is an issue id.
+fn reference<'a>(caps: &Captures<'a>) -> Option<(&'a str, bool)> {
+    if let Some(issue) = caps.name("issue") {
+        return Some((issue.as_str(), true));
+    }
+
+    let ticket = caps
+        .name("ticket")
+        .or_else(|| caps.get(1))
+        .or_else(|| caps.get(0))?;
+
+    Some((ticket.as_str(), false))
+}
+
+fn format_reference(caps: &Captures, config: &RefsConfig) -> Option<String> {
+    let (reference, is_issue) = reference(caps)?;
+    let footer = if is_issue {
+        &config.issue_footer
+    } else {
+        &config.ticket_footer
+    };
+
+    Some(footer.replace("{}", reference))
+}
+
+/// Finds the first issue id or ticket key in `text`, such as a branch description.
+pub fn first_reference(text: &str, pattern: &str) -> Result<Option<String>, APIError> {
+    let pattern = Regex::new(pattern).map_err(|e| APIError::new("Ticket pattern", e))?;
+
+    Ok(pattern
+        .captures_iter(text)
+        .find_map(|caps| reference(&caps).map(|(reference, _)| reference.to_string())))
+}
+
+/// Extracts the formatted reference footers for a branch name, in the order they appear.
+fn branch_references(branch: &str, config: &RefsConfig) -> Result<Vec<String>, APIError> {
+    let pattern = Regex::new(&config.pattern).map_err(|e| APIError::new("Ticket pattern", e))?;
+
+    let mut footers: Vec<String> = Vec::new();
+    for caps in pattern.captures_iter(branch) {
+        if let Some(footer) = format_reference(&caps, config)
+            && !footers.contains(&footer)
+        {
+            footers.push(footer);
+        }
+    }
+
+    Ok(footers)
+}
+
+/// Appends issue and ticket footers parsed from the current branch to a commit message.
+///
+/// Footers go through `git interpret-trailers`, so they join the message's trailer block.
+/// Footers already present in the message are not repeated, and a detached `HEAD`
+/// leaves the message untouched.
+pub fn append_reference_footers(message: &str, config: &RefsConfig) -> Result<String, APIError> {
+    let branch = git::current_branch()?;
+    if branch == "HEAD" {
+        return Ok(message.to_string());
+    }
+
+    let footers: Vec<String> = branch_references(&branch, config)?
+        .into_iter()
+        .filter(|footer| !message.contains(footer.as_str()))
+        .collect();
+
+    if footers.is_empty() {
+        return Ok(message.to_string());
+    }
+
+    if let Some(footer) = footers.iter().find(|footer| !footer.contains(':')) {
+        return Err(APIError::new_msg(
+            "Reference footers",
+            &format!("Footer '{}' must be written as 'Key: value'", footer),
+        ));
+    }
+
+    ui::Logger::dim(&format!(
+        "Adding {} reference(s) from branch {}",
+        footers.len(),
+        branch
+    ));
+
+    // Joins an existing trailer block, such as `Signed-off-by:`, instead of starting another.
+    let mut args = vec!["interpret-trailers", "--if-exists", "addIfDifferent"];
+    for footer in &footers {
+        args.push("--trailer");
+        args.push(footer);
+    }
+
+    git::git_cmd_with_input(&args, message, "git interpret-trailers")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATTERN: &str =
+        r"(?P<ticket>[A-Z][A-Z0-9]+-\d+)|(?:^|[\s/_-])(?:#|(?i:issue|gh)-)(?P<issue>\d+)\b";
+
+    fn config() -> RefsConfig {
+        RefsConfig {
+            pattern: PATTERN.to_string(),
+            ticket_footer: "Refs: {}".to_string(),
+            issue_footer: "Closes: #{}".to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_tickets_and_prefixed_issues() {
+        let refs = |branch| branch_references(branch, &config()).unwrap();
+        assert_eq!(refs("feature/PROJ-1234-login"), ["Refs: PROJ-1234"]);
+        assert_eq!(refs("fix/issue-42-crash"), ["Closes: #42"]);
+        assert_eq!(refs("fix/gh-7"), ["Closes: #7"]);
+        assert_eq!(refs("fix/#42-crash"), ["Closes: #42"]);
+    }
+
+    #[test]
+    fn ignores_dates_and_versions() {
+        let refs = |branch| branch_references(branch, &config()).unwrap();
+        assert!(refs("release/2024-01").is_empty());
+        assert!(refs("release/1.2.3").is_empty());
+        assert!(refs("fix/42-crash").is_empty());
+    }
+
+    #[test]
+    fn first_reference_reads_descriptions() {
+        assert_eq!(
+            first_reference("PROJ-12 add login", PATTERN).unwrap(),
+            Some("PROJ-12".to_string())
+        );
+        assert_eq!(
+            first_reference("fix #42 login", PATTERN).unwrap(),
+            Some("42".to_string())
+        );
+        assert_eq!(first_reference("add login", PATTERN).unwrap(), None);
+    }
+}
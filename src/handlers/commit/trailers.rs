@@ -0,0 +1,109 @@
+use crate::{
+    handlers::git,
+    models::{error::APIError, ui},
+};
+
+/// Trailers appended to a generated commit message.
+pub struct TrailersConfig {
+    /// Co-authors given as `Name <email>`.
+    pub co_authors: Vec<String>,
+    /// Ask the user to pick co-authors from the repository history.
+    pub pick_co_authors: bool,
+    /// Add a DCO `Signed-off-by:` line for the configured git user.
+    pub signoff: bool,
+    /// Extra trailers given as `Key: value`.
+    pub custom: Vec<String>,
+}
+
+impl TrailersConfig {
+    fn is_empty(&self) -> bool {
+        self.co_authors.is_empty()
+            && !self.pick_co_authors
+            && !self.signoff
+            && self.custom.is_empty()
+    }
+}
+
+/// Lists `Name <email>` identities from `git shortlog -sne`, most active first,
+/// leaving out the current git user.
+fn shortlog_authors() -> Result<Vec<String>, APIError> {
+    let output = git::git_cmd(&["shortlog", "-sne", "HEAD"], "git shortlog -sne")?;
+    let own_email = git::git_config("user.email").unwrap_or_default();
+
+    Ok(output
+        .lines()
+        .filter_map(|line| line.trim().split_once('\t'))
+        .map(|(_, author)| author.trim().to_string())
+        .filter(|author| own_email.is_empty() || !author.contains(&format!("<{}>", own_email)))
+        .collect())
+}
+
+fn pick_co_authors() -> Result<Vec<String>, APIError> {
+    let authors = shortlog_authors()?;
+    if authors.is_empty() {
+        ui::Logger::warning("No other authors found in the repository history.");
+        return Ok(Vec::new());
+    }
+
+    let options: Vec<&str> = authors.iter().map(|s| s.as_str()).collect();
+    let picked = ui::Input::multi_select("Who did you pair with?", &options);
+
+    Ok(picked.into_iter().map(|i| authors[i].clone()).collect())
+}
+
+fn collect_trailers(config: &TrailersConfig) -> Result<Vec<String>, APIError> {
+    let mut trailers = Vec::new();
+
+    let mut co_authors = config.co_authors.clone();
+    if config.pick_co_authors {
+        for author in pick_co_authors()? {
+            if !co_authors.contains(&author) {
+                co_authors.push(author);
+            }
+        }
+    }
+    trailers.extend(
+        co_authors
+            .iter()
+            .map(|author| format!("Co-authored-by: {}", author)),
+    );
+
+    if config.signoff {
+        let name = git::git_config("user.name")?;
+        let email = git::git_config("user.email")?;
+        trailers.push(format!("Signed-off-by: {} <{}>", name, email));
+    }
+
+    for trailer in &config.custom {
+        if !trailer.contains(':') {
+            return Err(APIError::new_msg(
+                "Commit trailers",
+                &format!("Trailer '{}' must be written as 'Key: value'", trailer),
+            ));
+        }
+        trailers.push(trailer.clone());
+    }
+
+    Ok(trailers)
+}
+
+/// Appends the configured trailers to a commit message through `git interpret-trailers`,
+/// so they merge into an existing trailer block and identical trailers are not repeated.
+pub fn append_trailers(message: &str, config: &TrailersConfig) -> Result<String, APIError> {
+    if config.is_empty() {
+        return Ok(message.to_string());
+    }
+
+    let trailers = collect_trailers(config)?;
+    if trailers.is_empty() {
+        return Ok(message.to_string());
+    }
+
+    let mut args = vec!["interpret-trailers", "--if-exists", "addIfDifferent"];
+    for trailer in &trailers {
+        args.push("--trailer");
+        args.push(trailer);
+    }
+
+    git::git_cmd_with_input(&args, message, "git interpret-trailers")
+}
@@ -0,0 +1,113 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        commit::{
+            diff::{get_commit_diff, get_range_diff},
+            filter::filter_diff,
+        },
+        git::{self, git_cmd},
+        markdown,
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+
+/// How much detail an explanation should go into.
+pub enum ExplainDepth {
+    Brief,
+    Standard,
+    Detailed,
+}
+
+impl ExplainDepth {
+    fn prompt(&self) -> &'static str {
+        match self {
+            ExplainDepth::Brief => prompts::EXPLAIN_BRIEF_PROMPT,
+            ExplainDepth::Standard => prompts::EXPLAIN_STANDARD_PROMPT,
+            ExplainDepth::Detailed => prompts::EXPLAIN_DETAILED_PROMPT,
+        }
+    }
+}
+
+/// Collects the commit messages and diff of a single revision or an `a..b` range.
+fn collect_changes(target: &str) -> Result<(String, String), APIError> {
+    if target.contains("..") {
+        let commits = git::log_commits(target)?;
+        if commits.is_empty() {
+            return Err(APIError::new_msg(
+                "Explain",
+                &format!("No commits found in {}", target),
+            ));
+        }
+        let messages = commits
+            .iter()
+            .rev()
+            .map(|commit| format!("- {}\n{}", commit.subject, commit.body))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok((messages, get_range_diff(target)?))
+    } else {
+        let header = git_cmd(
+            &[
+                "log",
+                "-1",
+                "--format=Commit: %H%nAuthor: %an%nDate: %ad%n%n%B",
+                target,
+            ],
+            &format!("git log {}", target),
+        )?;
+
+        Ok((header, get_commit_diff(target)?))
+    }
+}
+
+pub async fn handle_explain(
+    target: &str,
+    depth: ExplainDepth,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("EXPLAIN");
+    ui::Logger::step(&format!("Reading {}...", target));
+
+    let (messages, diff) = collect_changes(target)?;
+    let filtered_diff = filter_diff(&diff);
+
+    let context_message = format!(
+        r##"# COMMIT MESSAGES
+{}
+
+# DIFF
+{}"##,
+        messages, filtered_diff
+    );
+
+    let mut loader = InfiniteLoader::new("Explaining the changes");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let explanation = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::EXPLAIN_PROMPT,
+        &[&context_message, depth.prompt()],
+        "AI provider explanation",
+    )
+    .await?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Explanation done");
+
+    eprintln!();
+    println!("{}", markdown::handle_markdown_strip(&explanation));
+
+    Ok(())
+}
@@ -9,5 +9,5 @@ pub fn handle_json_strip(text: &str) -> String {
 
     json_str = json_str.trim();
 
-    return json_str.to_string();
+    json_str.to_string()
 }
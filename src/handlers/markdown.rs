@@ -0,0 +1,14 @@
+pub fn handle_markdown_strip(text: &str) -> String {
+    let mut markdown = text.trim();
+    for fence in ["```markdown", "```md"] {
+        if let Some(stripped) = markdown.strip_prefix(fence) {
+            markdown = stripped;
+            if let Some(stripped) = markdown.trim_end().strip_suffix("```") {
+                markdown = stripped;
+            }
+            break;
+        }
+    }
+
+    markdown.trim().to_string()
+}
@@ -1,6 +1,19 @@
 pub mod ai;
+pub mod branch;
+pub mod changelog;
 pub mod commit;
+pub mod explain;
 pub mod git;
 pub mod json;
+pub mod markdown;
+pub mod pr;
 pub mod readme;
+pub mod release_notes;
+pub mod resolve;
+pub mod review;
+pub mod reword;
+pub mod squash;
+pub mod stash;
+pub mod summary;
+pub mod tag;
 pub mod utils;
@@ -0,0 +1,162 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        commit::{diff::get_range_diff, filter::filter_diff},
+        git::{self, git_cmd},
+        markdown,
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+use std::{fs, path::Path};
+
+// Locations GitHub reads a single pull request template from, in lookup order.
+const PR_TEMPLATE_PATHS: [&str; 6] = [
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    "pull_request_template.md",
+    "PULL_REQUEST_TEMPLATE.md",
+    "docs/pull_request_template.md",
+    "docs/PULL_REQUEST_TEMPLATE.md",
+];
+
+/// Picks the branch to compare against: the given one (the positional target or
+/// `--pr-base`), the remote's default branch, or `main`.
+fn resolve_target_branch(target: Option<&str>) -> String {
+    if let Some(target) = target {
+        return target.to_string();
+    }
+
+    git_cmd(
+        &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"],
+        "symbolic-ref origin/HEAD",
+    )
+    .unwrap_or_else(|_| "main".to_string())
+}
+
+fn read_pr_template(repo_root: &str) -> Option<String> {
+    PR_TEMPLATE_PATHS
+        .iter()
+        .map(|path| Path::new(repo_root).join(path))
+        .find(|path| path.is_file())
+        .and_then(|path| fs::read_to_string(path).ok())
+        .filter(|template| !template.trim().is_empty())
+}
+
+/// Splits the model output into the title line and the Markdown body.
+fn split_title(text: &str) -> (String, String) {
+    let mut lines = text.lines();
+    let title = lines
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_start_matches('#')
+        .trim()
+        .to_string();
+    let body = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+
+    (title, body)
+}
+
+pub async fn handle_pr(
+    target: Option<&str>,
+    output: Option<&str>,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("PULL REQUEST");
+
+    let target_branch = resolve_target_branch(target);
+    let merge_base = git_cmd(
+        &["merge-base", &target_branch, "HEAD"],
+        &format!("git merge-base {} HEAD", target_branch),
+    )?;
+    let branch = git::current_branch()?;
+
+    ui::Logger::step(&format!(
+        "Comparing {} against {}...",
+        branch, target_branch
+    ));
+
+    let range = format!("{}..HEAD", merge_base);
+    let commits = git::log_commits(&range)?;
+    if commits.is_empty() {
+        return Err(APIError::new_msg(
+            "Pull request",
+            &format!(
+                "{} has no commits that are not on {}",
+                branch, target_branch
+            ),
+        ));
+    }
+    let commit_list = commits
+        .iter()
+        .rev()
+        .map(|commit| format!("- {}", commit.subject))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let diff = get_range_diff(&range)?;
+    let filtered_diff = filter_diff(&diff);
+
+    let mut user_messages = vec![format!(
+        r##"# BRANCH
+{} → {}
+
+# COMMITS (oldest first)
+{}
+
+# DIFF
+{}"##,
+        branch, target_branch, commit_list, filtered_diff
+    )];
+
+    let repo_root = git_cmd(
+        &["rev-parse", "--show-toplevel"],
+        "rev-parse --show-toplevel",
+    )?;
+    if let Some(template) = read_pr_template(&repo_root) {
+        ui::Logger::dim("Using the repository's pull request template.");
+        user_messages.push(prompts::PR_TEMPLATE_PROMPT.replace("{}", &template));
+    }
+
+    let mut loader = InfiniteLoader::new("Writing the pull request");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::PR_PROMPT,
+        &user_messages.iter().map(String::as_str).collect::<Vec<_>>(),
+        "AI provider pull request generation",
+    )
+    .await?;
+
+    let (title, body) = split_title(&markdown::handle_markdown_strip(&response));
+    let pr_markdown = format!("# {}\n\n{}\n", title, body);
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Pull request done");
+
+    match output {
+        Some(file_path) => {
+            fs::write(file_path, &pr_markdown).map_err(|e| APIError::new("fs::write", e))?;
+            ui::Logger::done("Pull request description successfully generated!");
+            ui::Logger::kv("Location", file_path);
+        }
+        None => {
+            eprintln!();
+            print!("{}", pr_markdown);
+        }
+    }
+
+    Ok(())
+}
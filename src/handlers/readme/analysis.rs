@@ -3,30 +3,49 @@ use crate::{
         ai,
         git::{collect_git_metadata, get_git_files},
         json,
-        readme::{file_filtering, prompts},
+        readme::{
+            answers::{AnswerOptions, Answers},
+            file_filtering, manifest, prompts, workspace,
+        },
     },
     models::{
         cli::{CliModel, Provider},
         error::APIError,
-        readme::ReadmeAnalysis,
+        readme::{ManifestFacts, ReadmeAnalysis, WorkspaceMember},
         ui,
     },
 };
-use file_filtering::filter_and_process_readme_files;
+use file_filtering::{filter_and_process_readme_files, token_budget};
 use prompts::analysis::README_ANALYSIS_PROMPT;
-use tokio_retry::{Retry, strategy::FixedInterval};
+use std::path::Path;
 
 struct RepositoryContext {
     file_contents: String,
     git_context: String,
+    manifests: Vec<ManifestFacts>,
+    members: Vec<WorkspaceMember>,
 }
 
-fn gather_repository_context() -> Result<RepositoryContext, APIError> {
+fn gather_repository_context(root: &Path, budget: usize) -> Result<RepositoryContext, APIError> {
     ui::Logger::step("Collecting repository files...");
     let files =
-        get_git_files().map_err(|_| APIError::new_msg("README", "Failed to get git files"))?;
+        get_git_files(root).map_err(|_| APIError::new_msg("README", "Failed to get git files"))?;
 
-    let file_contents = filter_and_process_readme_files(files.iter().map(AsRef::as_ref).collect())?;
+    let files: Vec<&str> = files.iter().map(AsRef::as_ref).collect();
+    let manifests = manifest::read_manifests(root, &files);
+    for facts in &manifests {
+        ui::Logger::dim(&format!("Read project facts from {}", facts.source));
+    }
+
+    let members = workspace::detect(root, &files);
+    if !members.is_empty() {
+        ui::Logger::dim(&format!(
+            "Found a workspace with {} packages",
+            members.len()
+        ));
+    }
+
+    let file_contents = filter_and_process_readme_files(root, files, &members, budget)?;
 
     ui::Logger::step("Gathering git metadata...");
     let git_context = collect_git_metadata()?;
@@ -34,6 +53,8 @@ fn gather_repository_context() -> Result<RepositoryContext, APIError> {
     Ok(RepositoryContext {
         file_contents,
         git_context,
+        manifests,
+        members,
     })
 }
 
@@ -41,6 +62,8 @@ async fn perform_ai_analysis(
     provider: Provider,
     model: Option<CliModel>,
     file_contents: &str,
+    manifests: Vec<ManifestFacts>,
+    members: Vec<WorkspaceMember>,
 ) -> Result<ReadmeAnalysis, APIError> {
     let provider_name = match provider {
         Provider::Gemini => "Gemini",
@@ -50,64 +73,114 @@ async fn perform_ai_analysis(
 
     let ai_provider = ai::create_provider(provider, model)?;
 
-    let attempts = 3; // TODO: Add custom attempts
-
     ui::Logger::step("Analyzing repository structure...");
 
-    let analysis_text = Retry::spawn(FixedInterval::from_millis(100).take(attempts), || async {
-        ai_provider
-            .generate_content(
-                Some(README_ANALYSIS_PROMPT),
-                vec![
-                    &file_contents,
-                    prompts::analysis::README_ANALYSIS_USER_PROMPT,
-                ],
-            )
-            .await
-    })
-    .await
-    .map_err(|e| APIError::new("AI provider Readme Analysis", e))?;
+    let manifest_facts = if manifests.is_empty() {
+        None
+    } else {
+        Some(
+            prompts::analysis::README_MANIFEST_FACTS_PROMPT
+                .replace("{}", &manifest::describe(&manifests)),
+        )
+    };
+
+    let workspace_packages = if members.is_empty() {
+        None
+    } else {
+        Some(
+            prompts::analysis::README_WORKSPACE_PROMPT
+                .replace("{}", &workspace::describe(&members)),
+        )
+    };
+
+    let mut user_messages = vec![file_contents];
+    user_messages.extend(manifest_facts.as_deref());
+    user_messages.extend(workspace_packages.as_deref());
+    user_messages.push(prompts::analysis::README_ANALYSIS_USER_PROMPT);
+
+    let analysis_text = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        README_ANALYSIS_PROMPT,
+        &user_messages,
+        "AI provider Readme Analysis",
+    )
+    .await?;
 
     let json_str = json::handle_json_strip(&analysis_text);
 
-    serde_json::from_str(&json_str).map_err(|e| APIError::new("Invalid analysis JSON", e))
+    let mut analysis: ReadmeAnalysis =
+        serde_json::from_str(&json_str).map_err(|e| APIError::new("Invalid analysis JSON", e))?;
+
+    for disagreement in manifest::apply(&mut analysis.extracted, &manifests) {
+        ui::Logger::warning(&format!("{} (using the manifest)", disagreement));
+    }
+    analysis.manifests = manifests;
+    analysis.members = members;
+
+    Ok(analysis)
 }
 
-fn collect_user_feedback(analysis: &ReadmeAnalysis) -> Vec<String> {
+fn collect_user_feedback(
+    analysis: &ReadmeAnalysis,
+    options: &AnswerOptions,
+) -> Result<Vec<String>, APIError> {
     ui::Logger::header("README CONFIGURATION");
 
+    let mut preset = Answers::load(options)?;
     let mut answers = Vec::new();
 
     for (i, q) in analysis.questions.iter().enumerate() {
-        let options: Vec<&str> = q.options.iter().map(|s| s.as_str()).collect();
-
         ui::Logger::dim(&format!("Question {}/{}", i + 1, analysis.questions.len()));
 
-        let selected_idx = ui::Input::select(&q.question, &options);
+        let answer = preset.answer(i, q)?;
 
         answers.push(format!(
             "Q{}: {}
 A: {}",
             i + 1,
             q.question,
-            options[selected_idx]
+            answer
         ));
 
-        println!();
+        eprintln!();
     }
-    answers
+
+    let unmatched = preset.unmatched();
+    if !unmatched.is_empty() {
+        ui::Logger::warning(&format!(
+            "No question matched these answers file entries: {}",
+            unmatched.join(", ")
+        ));
+    }
+
+    if let Some(path) = &options.save_answers {
+        preset.save(path)?;
+    }
+
+    Ok(answers)
 }
 
+/// Analyses the repository, or the workspace package, rooted at `root` and collects the
+/// answers to the model's questions.
 pub async fn analyze_readme_content(
+    root: &Path,
     provider: Provider,
     model: Option<CliModel>,
+    answer_options: &AnswerOptions,
 ) -> Result<(ReadmeAnalysis, String, Vec<String>), APIError> {
-    let context = gather_repository_context()?;
-    let analysis = perform_ai_analysis(provider, model, &context.file_contents).await?;
+    let context = gather_repository_context(root, token_budget(&provider, model.as_ref()))?;
+    let analysis = perform_ai_analysis(
+        provider,
+        model,
+        &context.file_contents,
+        context.manifests,
+        context.members,
+    )
+    .await?;
 
     ui::Logger::success("Analysis complete!");
 
-    let answers = collect_user_feedback(&analysis);
+    let answers = collect_user_feedback(&analysis, answer_options)?;
 
     Ok((analysis, context.git_context, answers))
 }
@@ -0,0 +1,280 @@
+use crate::models::{error::APIError, readme::Question, ui};
+use serde::Serialize;
+use std::fs;
+use toml::{Table, Value};
+
+/// Where the answers to the analysis questions come from, so README generation can run
+/// without a terminal.
+pub struct AnswerOptions {
+    /// TOML file mapping question text or number to an option number or text.
+    pub answers_file: Option<String>,
+    /// Take the first option for questions the answers file does not cover.
+    pub non_interactive: bool,
+    /// Write the chosen answers to this TOML file.
+    pub save_answers: Option<String>,
+}
+
+/// One entry of the answers file. Saved entries are tables with the question text and
+/// the answer under the question number; hand-written ones map a question text or number
+/// straight to the answer.
+struct PresetEntry {
+    key: String,
+    number: Option<usize>,
+    question: Option<String>,
+    value: Value,
+}
+
+#[derive(Serialize)]
+struct SavedAnswer<'a> {
+    question: &'a str,
+    answer: String,
+}
+
+pub struct Answers {
+    preset: Vec<PresetEntry>,
+    used: Vec<bool>,
+    chosen: Vec<(String, String)>,
+    non_interactive: bool,
+}
+
+/// Strips the `1: ` numbering the analysis prompt puts in front of options.
+fn option_label(option: &str) -> &str {
+    option
+        .split_once(':')
+        .filter(|(number, _)| number.trim().parse::<usize>().is_ok())
+        .map_or(option, |(_, label)| label)
+        .trim()
+}
+
+/// Matches a preset value against the options: a 1-based option number, or the option
+/// text with or without its numbering. Other text is kept as a free-text answer, while
+/// option numbers out of range and other value types are rejected.
+fn preset_choice(question: &str, value: &Value, options: &[String]) -> Result<String, APIError> {
+    let invalid = |reason: &str| {
+        APIError::new_msg(
+            "Answers file",
+            &format!("Invalid answer {} for \"{}\": {}", value, question, reason),
+        )
+    };
+    let by_number = |n: i64| {
+        usize::try_from(n)
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| options.get(i))
+            .cloned()
+            .ok_or_else(|| {
+                invalid(&format!(
+                    "expected an option number from 1 to {}",
+                    options.len()
+                ))
+            })
+    };
+
+    match value {
+        Value::Integer(n) => by_number(*n),
+        Value::String(text) => {
+            let text = text.trim();
+            if let Ok(n) = text.parse::<i64>() {
+                return by_number(n);
+            }
+            Ok(options
+                .iter()
+                .find(|option| {
+                    option.eq_ignore_ascii_case(text)
+                        || option_label(option).eq_ignore_ascii_case(text)
+                })
+                .cloned()
+                .unwrap_or_else(|| text.to_string()))
+        }
+        _ => Err(invalid("expected an option number or text")),
+    }
+}
+
+impl Answers {
+    pub fn load(options: &AnswerOptions) -> Result<Self, APIError> {
+        match &options.answers_file {
+            Some(path) => {
+                let content = fs::read_to_string(path)
+                    .map_err(|e| APIError::new_msg(&format!("Reading {}", path), &e.to_string()))?;
+                Self::parse(&content, path, options.non_interactive)
+            }
+            None => Self::parse("", "answers", options.non_interactive),
+        }
+    }
+
+    fn parse(content: &str, source: &str, non_interactive: bool) -> Result<Self, APIError> {
+        let table: Table = toml::from_str(content)
+            .map_err(|e| APIError::new_msg(&format!("Parsing {}", source), &e.to_string()))?;
+
+        let preset: Vec<PresetEntry> = table
+            .into_iter()
+            .map(|(key, value)| {
+                let number = key.trim().parse().ok();
+                match value {
+                    Value::Table(mut saved) if saved.contains_key("answer") => PresetEntry {
+                        question: saved
+                            .get("question")
+                            .and_then(Value::as_str)
+                            .map(str::to_string),
+                        value: saved.remove("answer").unwrap_or(Value::Boolean(false)),
+                        number,
+                        key,
+                    },
+                    value => PresetEntry {
+                        question: number.is_none().then(|| key.clone()),
+                        number,
+                        key,
+                        value,
+                    },
+                }
+            })
+            .collect();
+
+        Ok(Answers {
+            used: vec![false; preset.len()],
+            preset,
+            chosen: Vec::new(),
+            non_interactive,
+        })
+    }
+
+    /// Finds the unused entry for question `index`: by exact text, by text in any case, and
+    /// by question number last, since the model may word the same question differently.
+    fn preset_entry(&self, index: usize, question: &str) -> Option<usize> {
+        let unused = || {
+            self.preset
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !self.used[*i])
+        };
+
+        unused()
+            .find(|(_, entry)| entry.question.as_deref() == Some(question))
+            .or_else(|| {
+                unused().find(|(_, entry)| {
+                    entry
+                        .question
+                        .as_deref()
+                        .is_some_and(|text| text.trim().eq_ignore_ascii_case(question.trim()))
+                })
+            })
+            .or_else(|| unused().find(|(_, entry)| entry.number == Some(index + 1)))
+            .map(|(i, _)| i)
+    }
+
+    /// Answers question `index` from the answers file, the first option when running
+    /// non-interactively, or by asking the user. An answer in the file that matches no
+    /// option number is an error rather than a prompt, so CI runs do not block.
+    pub fn answer(&mut self, index: usize, question: &Question) -> Result<String, APIError> {
+        let preset = match self.preset_entry(index, &question.question) {
+            Some(i) => {
+                self.used[i] = true;
+                let entry = &self.preset[i];
+                if let Some(saved) = entry.question.as_deref()
+                    && !saved.trim().eq_ignore_ascii_case(question.question.trim())
+                {
+                    ui::Logger::dim(&format!(
+                        "Answering question {} with the entry saved for \"{}\"",
+                        index + 1,
+                        saved
+                    ));
+                }
+                Some(preset_choice(
+                    &question.question,
+                    &entry.value,
+                    &question.options,
+                )?)
+            }
+            None => None,
+        };
+
+        let answer = match preset {
+            Some(answer) => {
+                ui::Logger::kv(&question.question, &answer);
+                answer
+            }
+            None if self.non_interactive || question.options.is_empty() => {
+                let answer = question.options.first().cloned().unwrap_or_default();
+                ui::Logger::kv(&question.question, &format!("{} (default)", answer));
+                answer
+            }
+            None => {
+                let options: Vec<&str> = question.options.iter().map(String::as_str).collect();
+                options[ui::Input::select(&question.question, &options)].to_string()
+            }
+        };
+
+        self.chosen
+            .push((question.question.clone(), option_label(&answer).to_string()));
+        Ok(answer)
+    }
+
+    /// The keys of the answers file entries no question used.
+    pub fn unmatched(&self) -> Vec<&str> {
+        self.preset
+            .iter()
+            .zip(&self.used)
+            .filter(|(_, used)| !**used)
+            .map(|(entry, _)| entry.key.as_str())
+            .collect()
+    }
+
+    /// Writes the chosen answers under their question number, with the question text, so a
+    /// later run can match them by text or, when the wording changed, by number.
+    pub fn save(&self, path: &str) -> Result<(), APIError> {
+        let mut content = String::new();
+        for (i, (question, answer)) in self.chosen.iter().enumerate() {
+            let entry = toml::to_string(&SavedAnswer {
+                question,
+                answer: answer.clone(),
+            })
+            .map_err(|e| APIError::new_msg("Serializing answers", &e.to_string()))?;
+            if i > 0 {
+                content.push('\n');
+            }
+            content.push_str(&format!("[{}]\n{}", i + 1, entry));
+        }
+        fs::write(path, content).map_err(|e| APIError::new("fs::write", e))?;
+        ui::Logger::kv("Answers saved to", path);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(text: &str) -> Question {
+        Question {
+            question: text.to_string(),
+            options: vec!["1: Developers".to_string(), "2: Operators".to_string()],
+        }
+    }
+
+    #[test]
+    fn matches_by_text_then_number() {
+        let mut answers = Answers::parse(
+            r#"
+"Who is the audience?" = 2
+
+[2]
+question = "Which license fits?"
+answer = "Developers"
+
+[7]
+question = "Gone"
+answer = "1"
+"#,
+            "answers.toml",
+            true,
+        )
+        .unwrap();
+
+        let reworded = question("What license should it use?");
+        assert_eq!(answers.answer(1, &reworded).unwrap(), "1: Developers");
+        let audience = question("who is the audience?");
+        assert_eq!(answers.answer(0, &audience).unwrap(), "2: Operators");
+        assert_eq!(answers.unmatched(), ["7"]);
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    handlers::{git, readme::sections},
+    models::{error::APIError, readme::ManifestFacts},
+};
+use regex::Regex;
+use std::{fs, path::Path};
+
+const STYLE: &str = "style=flat-square";
+const WORKFLOWS_DIR: &str = ".github/workflows";
+
+/// A badge computed from repository facts.
+pub struct Badge {
+    label: String,
+    image: String,
+    link: Option<String>,
+}
+
+impl Badge {
+    fn markdown(&self) -> String {
+        let image = format!("![{}]({})", self.label, self.image);
+        match &self.link {
+            Some(link) => format!("[{}]({})", image, link),
+            None => image,
+        }
+    }
+}
+
+/// Escapes text for a static shields.io badge path segment.
+fn shields_escape(text: &str) -> String {
+    text.replace('-', "--")
+        .replace('_', "__")
+        .replace(' ', "%20")
+        .replace('/', "%2F")
+}
+
+fn static_badge(label: &str, message: &str, color: &str, link: Option<String>) -> Badge {
+    Badge {
+        label: label.to_string(),
+        image: format!(
+            "https://img.shields.io/badge/{}-{}-{}?{}",
+            shields_escape(label),
+            shields_escape(message),
+            color,
+            STYLE
+        ),
+        link,
+    }
+}
+
+/// Returns `(owner, repo)` for GitHub web URLs.
+fn github_repo(web_url: &str) -> Option<(&str, &str)> {
+    let path = web_url.strip_prefix("https://github.com/")?;
+    let (owner, repo) = path.split_once('/')?;
+    (!owner.is_empty() && !repo.is_empty() && !repo.contains('/')).then_some((owner, repo))
+}
+
+/// The workflow files of the repository, also when generating for a workspace package.
+fn workflow_files() -> Vec<String> {
+    let root = git::git_cmd(
+        &["rev-parse", "--show-toplevel"],
+        "rev-parse --show-toplevel",
+    )
+    .unwrap_or_else(|_| ".".to_string());
+    let mut files: Vec<String> = fs::read_dir(Path::new(&root).join(WORKFLOWS_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| name.ends_with(".yml") || name.ends_with(".yaml"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+fn version_badge(facts: &ManifestFacts) -> Option<Badge> {
+    let name = facts.name.as_deref()?;
+
+    let registry = match facts.source.as_str() {
+        _ if !facts.published => None,
+        "Cargo.toml" => Some((
+            format!("https://img.shields.io/crates/v/{}?{}", name, STYLE),
+            format!("https://crates.io/crates/{}", name),
+        )),
+        "package.json" => Some((
+            format!("https://img.shields.io/npm/v/{}?{}", name, STYLE),
+            format!("https://www.npmjs.com/package/{}", name),
+        )),
+        "pyproject.toml" => Some((
+            format!("https://img.shields.io/pypi/v/{}?{}", name, STYLE),
+            format!("https://pypi.org/project/{}", name),
+        )),
+        "go.mod" => Some((
+            format!("https://pkg.go.dev/badge/{}.svg", name),
+            format!("https://pkg.go.dev/{}", name),
+        )),
+        _ => None,
+    };
+
+    match registry {
+        Some((image, link)) => Some(Badge {
+            label: "Version".to_string(),
+            image,
+            link: Some(link),
+        }),
+        None => facts
+            .version
+            .as_deref()
+            .map(|version| static_badge("version", version, "blue", None)),
+    }
+}
+
+fn language(
+    root: &Path,
+    facts: &ManifestFacts,
+) -> Option<(&'static str, &'static str, &'static str)> {
+    match facts.source.as_str() {
+        "Cargo.toml" => Some(("Rust", "orange", "rust")),
+        "package.json" if root.join("tsconfig.json").exists() => {
+            Some(("TypeScript", "3178c6", "typescript"))
+        }
+        "package.json" => Some(("JavaScript", "f7df1e", "javascript")),
+        "pyproject.toml" => Some(("Python", "3776ab", "python")),
+        "go.mod" => Some(("Go", "00add8", "go")),
+        _ => None,
+    }
+}
+
+/// Computes the badges that can be verified from the manifests and license file in
+/// `root`, the workflow files and the `origin` remote.
+pub fn compute(root: &Path, manifests: &[ManifestFacts]) -> Vec<Badge> {
+    let web_url = git::origin_url()
+        .ok()
+        .and_then(|origin| git::remote_web_url(&origin));
+    let github = web_url.as_deref().and_then(github_repo);
+
+    let mut badges = Vec::new();
+
+    if let Some((owner, repo)) = github {
+        for workflow in workflow_files() {
+            badges.push(Badge {
+                label: workflow
+                    .trim_end_matches(".yml")
+                    .trim_end_matches(".yaml")
+                    .to_string(),
+                image: format!(
+                    "https://img.shields.io/github/actions/workflow/status/{}/{}/{}?{}",
+                    owner, repo, workflow, STYLE
+                ),
+                link: Some(format!(
+                    "https://github.com/{}/{}/actions/workflows/{}",
+                    owner, repo, workflow
+                )),
+            });
+        }
+    }
+
+    if let Some(badge) = manifests.iter().find_map(version_badge) {
+        badges.push(badge);
+    }
+
+    if let Some(license) = manifests.iter().find_map(|facts| facts.license.as_deref()) {
+        let link = ["LICENSE", "LICENSE.md", "LICENSE.txt"]
+            .into_iter()
+            .find(|file| root.join(file).exists())
+            .map(str::to_string);
+        badges.push(static_badge("license", license, "blue", link));
+    }
+
+    if let Some((name, color, logo)) = manifests.iter().find_map(|facts| language(root, facts)) {
+        let mut badge = static_badge("language", name, color, None);
+        badge.image.push_str(&format!("&logo={}", logo));
+        badges.push(badge);
+    }
+
+    if let Some(web_url) = &web_url {
+        badges.push(match github {
+            Some((owner, repo)) => Badge {
+                label: "GitHub".to_string(),
+                image: format!(
+                    "https://img.shields.io/github/stars/{}/{}?{}&logo=github",
+                    owner, repo, STYLE
+                ),
+                link: Some(web_url.clone()),
+            },
+            None => static_badge("repo", web_url, "black", Some(web_url.clone())),
+        });
+    }
+
+    badges
+}
+
+/// Whether a line holds nothing but badge images, such as `![Build](https://img.shields.io/...)`,
+/// optionally wrapped in links.
+fn is_badge_line(image: &Regex, line: &str) -> bool {
+    let line = line.trim();
+    let mut images = 0;
+    let rest = image.replace_all(line, |caps: &regex::Captures| {
+        let url = &caps["url"];
+        if url.contains("shields.io") || url.contains("badge") || url.contains("/workflows/") {
+            images += 1;
+            String::new()
+        } else {
+            caps[0].to_string()
+        }
+    });
+
+    images > 0 && rest.trim().is_empty()
+}
+
+/// Removes badge lines outside code fences and kept sections, and puts the computed
+/// badges under the title. A README whose hero section, the one with the title or the
+/// first one without a title, is kept is left untouched.
+pub fn inject(readme: &str, badges: &[Badge]) -> Result<String, APIError> {
+    let parsed = sections::parse(readme);
+    // The title is the first level 1 heading, which always starts a section since headings
+    // in code fences do not, also when a logo or an HTML block comes before it.
+    let title_section = parsed
+        .iter()
+        .position(|section| section.content.starts_with("# "));
+    if parsed
+        .get(title_section.unwrap_or(0))
+        .is_some_and(|section| section.is_kept())
+    {
+        return Ok(readme.to_string());
+    }
+
+    let image = Regex::new(r"\[?!\[[^\]]*\]\((?P<url>[^)\s]*)[^)]*\)(?:\]\([^)]*\))?")
+        .map_err(|e| APIError::new("Badge pattern", e))?;
+
+    let mut lines: Vec<&str> = Vec::new();
+    let mut title = None;
+    for (index, section) in parsed.iter().enumerate() {
+        if title_section == Some(index) {
+            title = Some(lines.len());
+        }
+        let mut in_fence = false;
+        for line in section.content.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+            if section.is_kept() || in_fence || !is_badge_line(&image, line) {
+                lines.push(line);
+            }
+        }
+    }
+
+    let badge_line = badges
+        .iter()
+        .map(Badge::markdown)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Below the title and its tagline blockquote, or at the top without a title.
+    let insert_at = match title {
+        Some(title) => {
+            let mut index = title + 1;
+            while index < lines.len()
+                && (lines[index].trim().is_empty() || lines[index].trim_start().starts_with('>'))
+            {
+                index += 1;
+            }
+            index
+        }
+        None => 0,
+    };
+
+    let mut output: Vec<&str> = lines[..insert_at].to_vec();
+    if !badges.is_empty() {
+        while output.last().is_some_and(|line| line.trim().is_empty()) {
+            output.pop();
+        }
+        if !output.is_empty() {
+            output.push("");
+        }
+        output.push(&badge_line);
+        output.push("");
+    }
+    output.extend_from_slice(&lines[insert_at..]);
+
+    Ok(format!("{}\n", output.join("\n").trim_end()))
+}
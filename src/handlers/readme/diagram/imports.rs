@@ -0,0 +1,244 @@
+use crate::models::error::APIError;
+use regex::Regex;
+use std::{
+    collections::BTreeSet,
+    path::{Component, Path, PathBuf},
+};
+
+/// The import statement patterns of the supported languages, compiled once per run.
+pub struct ImportPatterns {
+    rust_use: Regex,
+    js_import: Regex,
+    py_from: Regex,
+    py_import: Regex,
+    go_import: Regex,
+    go_path: Regex,
+}
+
+/// Resolves `.` and `..` in a relative path, returning it with `/` separators.
+fn normalize(path: &Path) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                parts.pop();
+            }
+            Component::Normal(part) => parts.push(part.to_string_lossy().to_string()),
+            _ => {}
+        }
+    }
+    parts.join("/")
+}
+
+/// Expands a Rust use tree such as `crate::a::{b, c::{d, e}}` into its paths.
+fn expand_use_tree(tree: &str) -> Vec<String> {
+    let tree = tree.trim();
+
+    let Some(open) = tree.find('{') else {
+        let path = tree.split(" as ").next().unwrap_or(tree).trim();
+        return vec![path.trim_end_matches("::self").to_string()];
+    };
+    let prefix = tree[..open].trim();
+    let inner = tree[open + 1..]
+        .strip_suffix('}')
+        .unwrap_or(&tree[open + 1..]);
+
+    let mut items = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&inner[start..]);
+
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .flat_map(|item| match item {
+            "self" => vec![prefix.trim_end_matches("::").to_string()],
+            item => expand_use_tree(&format!("{}{}", prefix, item)),
+        })
+        .collect()
+}
+
+/// The module path a Rust file defines: `src/a/mod.rs` and `src/a.rs` both define `src/a`,
+/// while `main.rs` and `lib.rs` define the crate root.
+fn rust_module(path: &Path) -> PathBuf {
+    let parent = path.parent().unwrap_or(Path::new(""));
+    match path.file_stem().and_then(|s| s.to_str()) {
+        Some("mod" | "main" | "lib") | None => parent.to_path_buf(),
+        Some(stem) => parent.join(stem),
+    }
+}
+
+/// The `src` directory the file belongs to, which `crate::` paths start from.
+fn rust_crate_root(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find(|dir| dir.file_name().is_some_and(|name| name == "src"))
+        .map_or_else(
+            || path.parent().unwrap_or(Path::new("")).to_path_buf(),
+            Path::to_path_buf,
+        )
+}
+
+fn rust_imports(pattern: &Regex, path: &Path, content: &str) -> Vec<String> {
+    let module = rust_module(path);
+    let crate_root = rust_crate_root(path);
+
+    pattern
+        .captures_iter(content)
+        .flat_map(|caps| expand_use_tree(&caps[1]))
+        .filter_map(|import| {
+            let mut segments = import.split("::");
+            let mut base = match segments.next()? {
+                "crate" => crate_root.clone(),
+                "self" => module.clone(),
+                "super" => module.parent()?.to_path_buf(),
+                // External crates and names already in scope.
+                _ => return None,
+            };
+            for segment in segments {
+                if segment == "super" {
+                    base = base.parent()?.to_path_buf();
+                } else {
+                    base.push(segment);
+                }
+            }
+            Some(normalize(&base))
+        })
+        .collect()
+}
+
+fn js_imports(pattern: &Regex, path: &Path, content: &str) -> Vec<String> {
+    let dir = path.parent().unwrap_or(Path::new(""));
+
+    pattern
+        .captures_iter(content)
+        .filter_map(|caps| {
+            let import = &caps[1];
+            if import.starts_with('.') {
+                Some(normalize(&dir.join(import)))
+            } else {
+                // The common `@/` alias for the source directory.
+                import
+                    .strip_prefix("@/")
+                    .map(|rest| format!("src/{}", rest))
+            }
+        })
+        .collect()
+}
+
+/// The repository paths an absolute Python import may point to: the module itself, and
+/// the module under `src/` when the repository uses that layout for it.
+fn python_absolute(module: String, known: &BTreeSet<String>) -> Vec<String> {
+    let in_src = format!("src/{}", module);
+    if known.contains(&in_src) {
+        vec![module, in_src]
+    } else {
+        vec![module]
+    }
+}
+
+fn python_imports(
+    from: &Regex,
+    import: &Regex,
+    path: &Path,
+    content: &str,
+    known: &BTreeSet<String>,
+) -> Vec<String> {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let mut targets = Vec::new();
+
+    for caps in from.captures_iter(content) {
+        let module = caps[2].replace('.', "/");
+        let dots = caps[1].len();
+        if dots == 0 {
+            targets.extend(python_absolute(module, known));
+            continue;
+        }
+
+        let mut base = dir.to_path_buf();
+        for _ in 1..dots {
+            base.pop();
+        }
+        if module.is_empty() {
+            // `from . import a, b` imports sibling modules.
+            for name in caps[3].split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                targets.push(normalize(&base.join(name)));
+            }
+        } else {
+            targets.push(normalize(&base.join(module)));
+        }
+    }
+    for caps in import.captures_iter(content) {
+        targets.extend(python_absolute(caps[1].replace('.', "/"), known));
+    }
+
+    targets
+}
+
+fn go_imports(pattern: &Regex, quoted: &Regex, content: &str, module: &str) -> Vec<String> {
+    let prefix = format!("{}/", module);
+
+    pattern
+        .captures_iter(content)
+        .flat_map(|caps| match (caps.get(1), caps.get(2)) {
+            (Some(block), _) => quoted
+                .captures_iter(block.as_str())
+                .map(|caps| caps[1].to_string())
+                .collect(),
+            (None, Some(single)) => vec![single.as_str().to_string()],
+            _ => Vec::new(),
+        })
+        .filter_map(|import| import.strip_prefix(&prefix).map(str::to_string))
+        .collect()
+}
+
+impl ImportPatterns {
+    pub fn new() -> Result<Self, APIError> {
+        let pattern = |p: &str| Regex::new(p).map_err(|e| APIError::new("Import pattern", e));
+
+        Ok(ImportPatterns {
+            rust_use: pattern(r"(?m)^\s*(?:pub(?:\([^)]*\))?\s+)?use\s+([^;]+);")?,
+            js_import: pattern(r#"(?:from\s*|import\s*\(?\s*|require\(\s*)['"]([^'"]+)['"]"#)?,
+            py_from: pattern(r"(?m)^\s*from\s+(\.*)([\w.]*)\s+import\s+\(?([\w \t,]+)")?,
+            py_import: pattern(r"(?m)^\s*import\s+([\w.]+)")?,
+            go_import: pattern(r#"(?m)^\s*import\s*(?:\(([^)]*)\)|(?:[\w.]+\s+)?"([^"]+)")"#)?,
+            go_path: pattern(r#""([^"]+)""#)?,
+        })
+    }
+
+    /// Lists the repository paths a source file imports, without extensions and possibly
+    /// followed by item names, such as `src/models/error/APIError`. Imports of external
+    /// packages are left out; `go_module` is the module path of the root `go.mod`, and
+    /// `known` holds the repository's files without extensions and its directories.
+    pub fn imports(
+        &self,
+        file: &str,
+        content: &str,
+        go_module: Option<&str>,
+        known: &BTreeSet<String>,
+    ) -> Vec<String> {
+        let path = Path::new(file);
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("rs") => rust_imports(&self.rust_use, path, content),
+            Some("js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs") => {
+                js_imports(&self.js_import, path, content)
+            }
+            Some("py") => python_imports(&self.py_from, &self.py_import, path, content, known),
+            Some("go") => go_module.map_or_else(Vec::new, |module| {
+                go_imports(&self.go_import, &self.go_path, content, module)
+            }),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,401 @@
+mod imports;
+
+use crate::{
+    handlers::{
+        ai,
+        git::get_git_files,
+        json,
+        readme::{file_filtering, manifest, prompts, sections},
+        utils::is_test_file,
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        readme::DiagramLabels,
+        ui,
+    },
+};
+use file_filtering::{files_by_dir, is_interesting_file};
+use imports::ImportPatterns;
+use prompts::diagram::DIAGRAM_LABEL_PROMPT;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+/// Larger diagrams are collapsed to shallower directories until they fit.
+const MAX_COMPONENTS: usize = 30;
+/// File names listed per component when asking for labels.
+const FILES_PER_COMPONENT: usize = 8;
+const CODE_EXTENSIONS: [&str; 20] = [
+    "rs", "go", "py", "js", "jsx", "ts", "tsx", "mjs", "cjs", "java", "kt", "kts", "swift", "cs",
+    "c", "h", "cpp", "hpp", "rb", "php",
+];
+
+/// The source directories of a repository and the imports between them.
+pub struct ModuleGraph {
+    /// Component directories with the files they contain, `""` being the root.
+    components: BTreeMap<String, Vec<String>>,
+    edges: BTreeSet<(String, String)>,
+}
+
+/// The component an import points into: the deepest directory that is the imported path
+/// or one of its parents.
+fn resolve<'a>(target: &str, dirs: &'a [String]) -> Option<&'a str> {
+    dirs.iter()
+        .filter(|dir| {
+            if dir.is_empty() {
+                !target.contains('/')
+            } else {
+                target == dir.as_str()
+                    || target
+                        .strip_prefix(dir.as_str())
+                        .is_some_and(|rest| rest.starts_with('/'))
+            }
+        })
+        .max_by_key(|dir| dir.len())
+        .map(String::as_str)
+}
+
+fn collapse(dir: &str, depth: usize) -> String {
+    dir.split('/').take(depth).collect::<Vec<_>>().join("/")
+}
+
+/// Builds the graph from the directories of the source files under `root`, as in the tree
+/// view, and their import statements. Nothing about the structure is left to the model.
+pub fn build(
+    root: &Path,
+    files: &[&str],
+    go_module: Option<&str>,
+) -> Result<ModuleGraph, APIError> {
+    let patterns = ImportPatterns::new()?;
+
+    let sources: Vec<&str> = files
+        .iter()
+        .copied()
+        .filter(|file| !is_test_file(file) && is_interesting_file(file))
+        .filter(|file| {
+            Path::new(file)
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| CODE_EXTENSIONS.contains(&e))
+        })
+        .collect();
+
+    let by_dir = files_by_dir(&sources);
+    let dirs: Vec<String> = by_dir.keys().cloned().collect();
+
+    // Every file without its extension, and every directory, as imports name them.
+    let mut known = BTreeSet::new();
+    for file in files {
+        let path = Path::new(file);
+        known.insert(path.with_extension("").to_string_lossy().to_string());
+        known.extend(
+            path.ancestors()
+                .skip(1)
+                .map(|dir| dir.to_string_lossy().to_string()),
+        );
+    }
+
+    let mut raw_edges = BTreeSet::new();
+    for file in &sources {
+        let Ok(content) = fs::read_to_string(root.join(file)) else {
+            continue;
+        };
+        let from = Path::new(file)
+            .parent()
+            .and_then(|p| p.to_str())
+            .unwrap_or("");
+        for target in patterns.imports(file, &content, go_module, &known) {
+            if let Some(to) = resolve(&target, &dirs) {
+                raw_edges.insert((from.to_string(), to.to_string()));
+            }
+        }
+    }
+
+    let mut depth = dirs
+        .iter()
+        .map(|dir| dir.split('/').count())
+        .max()
+        .unwrap_or(1);
+    while depth > 1
+        && dirs
+            .iter()
+            .map(|dir| collapse(dir, depth))
+            .collect::<BTreeSet<_>>()
+            .len()
+            > MAX_COMPONENTS
+    {
+        depth -= 1;
+    }
+
+    let mut components: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (dir, names) in by_dir {
+        components
+            .entry(collapse(&dir, depth))
+            .or_default()
+            .extend(names);
+    }
+    let edges = raw_edges
+        .into_iter()
+        .map(|(from, to)| (collapse(&from, depth), collapse(&to, depth)))
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    Ok(ModuleGraph { components, edges })
+}
+
+fn display_path(dir: &str) -> &str {
+    if dir.is_empty() { "(root)" } else { dir }
+}
+
+/// Asks the model to name each component. Labels for unknown components are dropped, so
+/// the model cannot change the structure.
+async fn label(
+    graph: &ModuleGraph,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<BTreeMap<String, String>, APIError> {
+    let components = graph
+        .components
+        .iter()
+        .map(|(dir, names)| {
+            let mut names = names.clone();
+            names.sort();
+            let more = names.len().saturating_sub(FILES_PER_COMPONENT);
+            names.truncate(FILES_PER_COMPONENT);
+            let more = if more > 0 {
+                format!(" and {} more", more)
+            } else {
+                String::new()
+            };
+            format!("- {}: {}{}", display_path(dir), names.join(", "), more)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let dependencies = graph
+        .edges
+        .iter()
+        .map(|(from, to)| format!("- {} -> {}", display_path(from), display_path(to)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let context_message = format!(
+        "# COMPONENTS\n{}\n\n# DEPENDENCIES\n{}",
+        components,
+        if dependencies.is_empty() {
+            "None"
+        } else {
+            &dependencies
+        }
+    );
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        DIAGRAM_LABEL_PROMPT,
+        &[&context_message],
+        "AI provider diagram labelling",
+    )
+    .await?;
+
+    let labels: DiagramLabels = serde_json::from_str(&json::handle_json_strip(&response))
+        .map_err(|e| APIError::new("Invalid diagram labels JSON", e))?;
+
+    Ok(labels
+        .labels
+        .into_iter()
+        .filter_map(|(path, label)| {
+            let dir = if path == "(root)" {
+                String::new()
+            } else {
+                path
+            };
+            let label: String = label.chars().take(40).collect();
+            (graph.components.contains_key(&dir) && !label.trim().is_empty())
+                .then(|| (dir, label.trim().to_string()))
+        })
+        .collect())
+}
+
+/// Escapes node text for Mermaid, which reads `#...;` entity codes even in quoted text.
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '#' => "#35;".to_string(),
+            '"' => "#quot;".to_string(),
+            '<' => "#lt;".to_string(),
+            '>' => "#gt;".to_string(),
+            '[' => "#91;".to_string(),
+            ']' => "#93;".to_string(),
+            '{' => "#123;".to_string(),
+            '}' => "#125;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Renders the graph as a Mermaid flowchart in a fenced code block. Labels and paths are
+/// escaped, since directory names may hold any character.
+pub fn render(graph: &ModuleGraph, labels: &BTreeMap<String, String>) -> String {
+    let ids: BTreeMap<&str, String> = graph
+        .components
+        .keys()
+        .enumerate()
+        .map(|(index, dir)| (dir.as_str(), format!("n{}", index)))
+        .collect();
+
+    let mut lines = vec!["```mermaid".to_string(), "flowchart TD".to_string()];
+    for dir in graph.components.keys() {
+        let path = escape(display_path(dir));
+        let text = match labels.get(dir) {
+            Some(label) => format!("{}<br/><small>{}</small>", escape(label), path),
+            None => path,
+        };
+        lines.push(format!("    {}[\"{}\"]", ids[dir.as_str()], text));
+    }
+    for (from, to) in &graph.edges {
+        lines.push(format!(
+            "    {} --> {}",
+            ids[from.as_str()],
+            ids[to.as_str()]
+        ));
+    }
+    lines.push("```".to_string());
+
+    lines.join("\n")
+}
+
+/// Builds, labels and renders the architecture diagram of the repository, or the workspace
+/// package, rooted at `root`. Returns `None` when there are no source files to draw.
+pub async fn generate(
+    root: &Path,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<Option<String>, APIError> {
+    ui::Logger::step("Building the module graph from imports...");
+    let files = get_git_files(root)?;
+    let files: Vec<&str> = files.iter().map(AsRef::as_ref).collect();
+
+    let manifests = manifest::read_manifests(root, &files);
+    let go_module = manifests
+        .iter()
+        .find(|facts| facts.source == "go.mod")
+        .and_then(|facts| facts.name.as_deref());
+
+    let graph = build(root, &files, go_module)?;
+    if graph.components.is_empty() {
+        ui::Logger::warning("No source files found to draw an architecture diagram from.");
+        return Ok(None);
+    }
+    ui::Logger::dim(&format!(
+        "Found {} components and {} dependencies",
+        graph.components.len(),
+        graph.edges.len()
+    ));
+
+    ui::Logger::step("Labelling components...");
+    // The structure stands on its own, so unlabelled components are better than nothing.
+    let labels = label(&graph, provider, model).await.unwrap_or_else(|e| {
+        ui::Logger::warning(&format!("Using directory names as labels: {}", e));
+        BTreeMap::new()
+    });
+
+    Ok(Some(render(&graph, &labels)))
+}
+
+fn is_architecture(heading: Option<&str>) -> bool {
+    heading.is_some_and(|heading| heading.to_lowercase().contains("architecture"))
+}
+
+/// Puts the diagram in place of the first Mermaid block of a section and drops the
+/// others, or appends it when the section has none.
+fn replace_mermaid(content: &str, diagram: &str) -> String {
+    let mut output = String::new();
+    let mut in_mermaid = false;
+    let mut replaced = false;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if !in_mermaid && trimmed.starts_with("```mermaid") {
+            in_mermaid = true;
+        } else if in_mermaid && trimmed.starts_with("```") {
+            in_mermaid = false;
+            if !replaced {
+                output.push_str(diagram);
+                output.push('\n');
+                replaced = true;
+            }
+        } else if !in_mermaid {
+            output.push_str(line);
+        }
+    }
+
+    if replaced {
+        output
+    } else {
+        format!("{}\n\n{}", output.trim_end(), diagram)
+    }
+}
+
+/// Puts the diagram into the README's architecture section, replacing its Mermaid
+/// diagram, or adds that section before the contributing or license section. A kept
+/// architecture section is left untouched.
+pub fn insert(readme: &str, diagram: &str) -> String {
+    let mut parsed = sections::parse(readme);
+
+    match parsed
+        .iter_mut()
+        .find(|section| is_architecture(section.heading.as_deref()))
+    {
+        Some(section) if section.is_kept() => return readme.to_string(),
+        Some(section) => {
+            section.content = replace_mermaid(&section.content, diagram);
+        }
+        None => {
+            let position = parsed
+                .iter()
+                .position(|section| {
+                    section.heading.as_deref().is_some_and(|heading| {
+                        let heading = heading.to_lowercase();
+                        ["contribut", "license", "acknowledg"]
+                            .iter()
+                            .any(|topic| heading.contains(topic))
+                    })
+                })
+                .unwrap_or(parsed.len());
+            parsed.insert(
+                position,
+                sections::Section {
+                    heading: Some("Architecture".to_string()),
+                    content: format!("## Architecture\n\n{}", diagram),
+                },
+            );
+        }
+    }
+
+    let merged: String = parsed
+        .iter()
+        .map(|section| sections::as_block(&section.content))
+        .collect();
+    format!("{}\n", merged.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_node_text() {
+        let graph = ModuleGraph {
+            components: BTreeMap::from([("src/[id]".to_string(), Vec::new())]),
+            edges: BTreeSet::new(),
+        };
+        let labels = BTreeMap::from([("src/[id]".to_string(), "Route <\"#1\">".to_string())]);
+
+        let rendered = render(&graph, &labels);
+        assert!(rendered.contains(
+            r#"n0["Route #lt;#quot;#35;1#quot;#gt;<br/><small>src/#91;id#93;</small>"]"#
+        ));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    handlers::{ai, markdown, readme::prompts},
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        readme::{DocKind, ReadmeAnalysis},
+        ui,
+    },
+};
+use std::{fs, path::Path};
+
+use super::generator::build_generation_prompt;
+
+const FILE_MARKER: &str = "<!-- gitz:file ";
+
+fn system_prompt(kind: DocKind) -> &'static str {
+    match kind {
+        DocKind::Contributing => prompts::docs::CONTRIBUTING_PROMPT,
+        DocKind::Security => prompts::docs::SECURITY_PROMPT,
+        DocKind::CodeOfConduct => prompts::docs::CODE_OF_CONDUCT_PROMPT,
+        DocKind::IssueTemplates => prompts::docs::ISSUE_TEMPLATES_PROMPT,
+        DocKind::Usage => prompts::docs::USAGE_GUIDE_PROMPT,
+    }
+}
+
+/// Splits a response made of `<!-- gitz:file name -->` sections into file names and
+/// contents. Names are reduced to their file name so nothing is written outside `dir`.
+fn split_files(content: &str) -> Vec<(String, String)> {
+    let mut files: Vec<(String, String)> = Vec::new();
+
+    for line in content.lines() {
+        let marker = line
+            .trim()
+            .strip_prefix(FILE_MARKER)
+            .and_then(|rest| rest.strip_suffix("-->"))
+            .and_then(|name| Path::new(name.trim()).file_name())
+            .map(|name| name.to_string_lossy().to_string());
+
+        match (marker, files.last_mut()) {
+            (Some(name), _) => files.push((name, String::new())),
+            (None, Some((_, body))) => {
+                body.push_str(line);
+                body.push('\n');
+            }
+            (None, None) => {}
+        }
+    }
+
+    files
+        .into_iter()
+        .map(|(name, body)| (name, format!("{}\n", body.trim())))
+        .filter(|(name, body)| !name.is_empty() && !body.trim().is_empty())
+        .collect()
+}
+
+/// Writes `content` to `path`, asking before replacing an existing file.
+fn write_document(path: &Path, content: &str) -> Result<bool, APIError> {
+    if path.exists()
+        && !ui::Input::confirm(&format!("{} already exists. Overwrite it?", path.display()))
+    {
+        ui::Logger::warning(&format!("Skipped {}", path.display()));
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| APIError::new("fs::create_dir_all", e))?;
+    }
+    fs::write(path, content).map_err(|e| APIError::new("fs::write", e))?;
+    ui::Logger::kv("Location", &path.display().to_string());
+
+    Ok(true)
+}
+
+/// Generates the document and writes it to `path`.
+pub async fn generate_document(
+    kind: DocKind,
+    path: &Path,
+    provider: Provider,
+    model: Option<CliModel>,
+    analysis: ReadmeAnalysis,
+    git_context: String,
+    answers: Vec<String>,
+) -> Result<(), APIError> {
+    ui::Logger::step(&format!("Generating the {}...", kind.title()));
+
+    let context_message =
+        build_generation_prompt(&analysis, &git_context, &answers, &path.to_string_lossy());
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    let content = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        system_prompt(kind),
+        &[&context_message],
+        "AI provider document generation",
+    )
+    .await?;
+
+    let content = markdown::handle_markdown_strip(&content);
+
+    let mut written = 0;
+    if kind == DocKind::IssueTemplates {
+        let files = split_files(&content);
+        if files.is_empty() {
+            return Err(APIError::new_msg(
+                "Issue templates",
+                "The AI provider returned no templates.",
+            ));
+        }
+        for (name, body) in files {
+            if write_document(&path.join(name), &body)? {
+                written += 1;
+            }
+        }
+    } else if write_document(path, &format!("{}\n", content))? {
+        written += 1;
+    }
+
+    if written > 0 {
+        ui::Logger::done(&format!("The {} was successfully generated!", kind.title()));
+    }
+
+    Ok(())
+}
@@ -1,42 +1,72 @@
-use crate::handlers::utils::is_config_file;
+mod ranking;
+mod signatures;
+
+use crate::handlers::git::file_churn;
+use crate::handlers::utils::{is_config_file, is_test_file};
+use crate::models::cli::{CliModel, Provider};
 use crate::models::error::APIError;
+use crate::models::readme::WorkspaceMember;
+use ranking::Candidate;
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
-const MAX_FILE_CONTENT_LENGTH: usize = 2000;
-const MAX_TOTAL_CONTENT_LENGTH: usize = 10000;
+/// Rough characters per token for source code, used to estimate prompt sizes.
+const CHARS_PER_TOKEN: usize = 4;
+/// Commits scanned to find the files that change most.
+const CHURN_COMMITS: usize = 500;
+/// Files larger than this are skipped, as they are usually generated or vendored.
+const MAX_FILE_SIZE: u64 = 512 * 1024;
 
-/// Checks if a file is likely a test file based on its path and name.
-fn is_test_file(file_path: &str) -> bool {
-    let path = Path::new(file_path);
+/// Tokens of repository content to send for the analysis, leaving room in the model's
+/// context window for the prompts and the answer.
+pub fn token_budget(provider: &Provider, model: Option<&CliModel>) -> usize {
+    match (provider, model) {
+        (_, Some(CliModel::Llama31_8B | CliModel::Llama31_70B)) => 4_000,
+        (Provider::Cerebras, _) => 30_000,
+        (Provider::Gemini, _) => 100_000,
+    }
+}
 
-    // Check for test-related path components (e.g., /tests/, /spec/)
-    if path.components().any(|c| {
-        let comp = c.as_os_str().to_string_lossy().to_lowercase();
-        matches!(
-            comp.as_str(),
-            "tests" | "test" | "spec" | "specs" | "__tests__"
-        )
-    }) {
-        return true;
+fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Cuts `text` to about `tokens` tokens at a line boundary.
+fn truncate_to_tokens(text: &str, tokens: usize, note: &str) -> String {
+    let limit = tokens * CHARS_PER_TOKEN;
+    if text.len() <= limit {
+        return text.to_string();
     }
 
-    // Check for common test file naming conventions
-    let file_name = path
-        .file_name()
-        .and_then(|s| s.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-    file_name.starts_with("test_")
-        || file_name.ends_with("_test.go")
-        || file_name.ends_with("_test.py")
-        || file_name.contains(".test.")
-        || file_name.contains(".spec.")
+    let mut cut_off_point = limit;
+    while cut_off_point > 0 && !text.is_char_boundary(cut_off_point) {
+        cut_off_point -= 1;
+    }
+    let cut_off_point = text[..cut_off_point].rfind('\n').unwrap_or(cut_off_point);
+    format!("{}\n... ({})\n", &text[..cut_off_point], note)
+}
+
+/// The text sent for one file: the whole file if it is small enough, its declarations
+/// otherwise, and its beginning for languages without a known syntax.
+fn render_file(candidate: &Candidate, max_tokens: usize) -> String {
+    let body = if estimate_tokens(&candidate.content) <= max_tokens {
+        candidate.content.clone()
+    } else {
+        match signatures::extract(candidate.path, &candidate.content) {
+            Some(signatures) => format!(
+                "(signatures only, bodies omitted)\n{}",
+                truncate_to_tokens(&signatures, max_tokens, "signatures truncated")
+            ),
+            None => truncate_to_tokens(&candidate.content, max_tokens, "file truncated"),
+        }
+    };
+
+    format!("---\nFile: {} ---\n{}\n\n", candidate.path, body.trim_end())
 }
 
 /// Checks if a file is "interesting" for codebase analysis, ignoring priority files.
-fn is_interesting_file(file_path: &str) -> bool {
+pub fn is_interesting_file(file_path: &str) -> bool {
     let path = Path::new(file_path);
     let file_name = path
         .file_name()
@@ -46,10 +76,10 @@ fn is_interesting_file(file_path: &str) -> bool {
 
     // Rule 1: Check for important root-level configuration files.
     // Checks if the file is in the root directory (parent is "" or ".").
-    if path.parent().map_or(true, |p| {
-        p.as_os_str().is_empty() || p.to_str() == Some(".")
-    }) {
-        if matches!(
+    if path
+        .parent()
+        .is_none_or(|p| p.as_os_str().is_empty() || p.to_str() == Some("."))
+        && matches!(
             file_name.as_str(),
             "dockerfile"
                 | "docker-compose.yml"
@@ -57,9 +87,9 @@ fn is_interesting_file(file_path: &str) -> bool {
                 | "makefile"
                 | "build.gradle"
                 | "pom.xml"
-        ) {
-            return true;
-        }
+        )
+    {
+        return true;
     }
 
     let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
@@ -74,6 +104,8 @@ fn is_interesting_file(file_path: &str) -> bool {
                 | Some("lib")
                 | Some("include")
                 | Some("cmd")
+                | Some("internal")
+                | Some("pkg")
                 | Some("server")
                 | Some("core")
         )
@@ -114,7 +146,9 @@ fn is_interesting_file(file_path: &str) -> bool {
     )
 }
 
-fn generate_tree_view(files: &[&str]) -> String {
+/// Groups file names by their directory, the structure the tree view and the
+/// architecture diagram are built from.
+pub fn files_by_dir(files: &[&str]) -> BTreeMap<String, Vec<String>> {
     let mut files_by_dir: BTreeMap<String, Vec<String>> = BTreeMap::new();
     for file in files {
         let path = Path::new(file);
@@ -130,9 +164,12 @@ fn generate_tree_view(files: &[&str]) -> String {
             .to_string();
         files_by_dir.entry(parent).or_default().push(file_name);
     }
+    files_by_dir
+}
 
+fn generate_tree_view(files: &[&str]) -> String {
     let mut tree = String::new();
-    for (dir, file_list) in files_by_dir {
+    for (dir, file_list) in files_by_dir(files) {
         tree.push_str(&format!("{}\n", dir));
         let mut sorted_files = file_list;
         sorted_files.sort();
@@ -148,87 +185,106 @@ fn generate_tree_view(files: &[&str]) -> String {
     tree
 }
 
-pub fn filter_and_process_readme_files(files: Vec<&str>) -> Result<String, APIError> {
-    let mut content = String::new();
-    let mut total_len = 0;
-
-    let mut priority_files = Vec::new();
-    let mut interesting_files = Vec::new();
+/// Renders the tree view and the highest-ranked files under `root`, where `files` are
+/// relative to `root`.
+pub fn filter_and_process_readme_files(
+    root: &Path,
+    files: Vec<&str>,
+    members: &[WorkspaceMember],
+    budget: usize,
+) -> Result<String, APIError> {
+    let mut candidates = Vec::new();
 
-    // Partition files into priority, interesting, and ignored.
     for &file in &files {
-        if is_test_file(file) {
-            continue; // Explicitly ignore test files
+        if is_test_file(file) || !(is_config_file(file) || is_interesting_file(file)) {
+            continue;
         }
-
-        if is_config_file(file) {
-            priority_files.push(file);
-        } else if is_interesting_file(file) {
-            interesting_files.push(file);
+        // Binary, oversized and unreadable files tell nothing useful.
+        let path = root.join(file);
+        if fs::metadata(&path).is_ok_and(|meta| meta.len() > MAX_FILE_SIZE) {
+            continue;
+        }
+        if let Ok(content) = fs::read_to_string(&path) {
+            candidates.push(Candidate {
+                path: file,
+                content,
+            });
         }
     }
 
-    // Sort for deterministic output
-    priority_files.sort();
-    interesting_files.sort();
-
-    let all_relevant_files: Vec<&str> = priority_files
-        .iter()
-        .chain(interesting_files.iter())
-        .cloned()
-        .collect();
-
-    let tree_view = generate_tree_view(&all_relevant_files);
-    content.push_str("Repository file structure:\n");
-    content.push_str(&tree_view);
-    content.push_str("\n\n");
-
-    content.push_str("Key file contents:\n");
-
-    let process_files = |file_paths: &[&str],
-                         content: &mut String,
-                         total_len: &mut usize|
-     -> Result<(), APIError> {
-        for file_path in file_paths {
-            if *total_len > MAX_TOTAL_CONTENT_LENGTH {
-                break;
+    let mut relevant_files: Vec<&str> = candidates.iter().map(|c| c.path).collect();
+    relevant_files.sort();
+
+    let mut content = String::from("Repository file structure:\n");
+    content.push_str(&truncate_to_tokens(
+        &generate_tree_view(&relevant_files),
+        budget / 4,
+        "tree truncated",
+    ));
+    content.push_str("\n\nKey file contents:\n");
+
+    ranking::rank(
+        &mut candidates,
+        &file_churn(root, CHURN_COMMITS).unwrap_or_default(),
+    );
+
+    // No single file may take more than an eighth of the budget.
+    let max_file_tokens = (budget / 8).max(500);
+
+    // Each workspace package gets its own share of the budget, and the files outside the
+    // packages the first one. What a group leaves unused goes to the following groups.
+    let mut groups: Vec<(Option<&WorkspaceMember>, Vec<&Candidate>)> = vec![(None, Vec::new())];
+    groups.extend(members.iter().map(|member| (Some(member), Vec::new())));
+    for candidate in &candidates {
+        let owner = members
+            .iter()
+            .enumerate()
+            .filter(|(_, member)| candidate.path.starts_with(&format!("{}/", member.path)))
+            .max_by_key(|(_, member)| member.path.len())
+            .map_or(0, |(index, _)| index + 1);
+        groups[owner].1.push(candidate);
+    }
+
+    let mut remaining = budget.saturating_sub(estimate_tokens(&content));
+    let mut omitted = 0;
+    let group_count = groups.len();
+
+    for (index, (member, group)) in groups.into_iter().enumerate() {
+        if group.is_empty() {
+            continue;
+        }
+        if let Some(member) = member {
+            content.push_str(&format!(
+                "\n# Package {} ({})\n",
+                member.facts.name.as_deref().unwrap_or(&member.path),
+                member.path
+            ));
+        }
+
+        let mut share = remaining / (group_count - index);
+        remaining -= share;
+
+        for candidate in group {
+            // A few lines of a file are not worth its header.
+            if share < 100 {
+                omitted += 1;
+                continue;
             }
-            let mut file_content = fs::read_to_string(file_path)
-                .map_err(|e| APIError::new("fs::read_to_string", e))?;
-
-            content.push_str(&format!("---\nFile: {} ---\n", file_path));
-            if file_content.len() > MAX_FILE_CONTENT_LENGTH {
-                let mut cut_off_point = MAX_FILE_CONTENT_LENGTH;
-                while cut_off_point > 0 && !file_content.is_char_boundary(cut_off_point) {
-                    cut_off_point -= 1;
-                }
-                file_content.truncate(cut_off_point);
-                file_content.push('\n');
-                file_content.push_str("... (file truncated)");
-                file_content.push('\n');
+            let rendered = render_file(candidate, max_file_tokens.min(share));
+            let tokens = estimate_tokens(&rendered);
+            if tokens <= share {
+                content.push_str(&rendered);
+                share -= tokens;
+            } else {
+                omitted += 1;
             }
-            *total_len += file_content.len();
-            content.push_str(&file_content);
-            content.push('\n');
-            content.push('\n');
         }
-        Ok(())
-    };
 
-    process_files(&priority_files, &mut content, &mut total_len)?;
-    process_files(&interesting_files, &mut content, &mut total_len)?;
+        remaining += share;
+    }
 
-    if total_len > MAX_TOTAL_CONTENT_LENGTH {
-        let mut cut_off_point = content
-            .char_indices()
-            .nth(MAX_TOTAL_CONTENT_LENGTH)
-            .map_or(content.len(), |(idx, _)| idx);
-        while !content.is_char_boundary(cut_off_point) && cut_off_point > 0 {
-            cut_off_point -= 1;
-        }
-        content.truncate(cut_off_point);
-        content.push_str("... (total content truncated)");
-        content.push('\n');
+    if omitted > 0 {
+        content.push_str(&format!("... ({} lower-ranked files omitted)\n", omitted));
     }
 
     Ok(content)
@@ -0,0 +1,114 @@
+use super::signatures::public_declarations;
+use crate::handlers::utils::is_config_file;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// A file considered for the README context, with its content.
+pub struct Candidate<'a> {
+    pub path: &'a str,
+    pub content: String,
+}
+
+fn is_entry_point(path: &str) -> bool {
+    matches!(
+        Path::new(path).file_name().and_then(|s| s.to_str()),
+        Some(
+            "main.rs"
+                | "lib.rs"
+                | "main.go"
+                | "main.py"
+                | "__main__.py"
+                | "app.py"
+                | "cli.py"
+                | "index.js"
+                | "index.ts"
+                | "index.jsx"
+                | "index.tsx"
+                | "main.js"
+                | "main.ts"
+                | "app.js"
+                | "app.ts"
+                | "server.js"
+                | "server.ts"
+                | "Program.cs"
+                | "Main.java"
+                | "Main.kt"
+        )
+    )
+}
+
+/// The name other files use to refer to a module: the file stem, or the directory name
+/// for `mod.rs`, `index.ts` and `__init__.py`. Names too generic to count are skipped.
+fn module_name(path: &str) -> Option<&str> {
+    let path = Path::new(path);
+    let stem = path.file_stem().and_then(|s| s.to_str())?;
+    let name = match stem {
+        "mod" | "index" | "__init__" => path.parent()?.file_name()?.to_str()?,
+        _ => stem,
+    };
+
+    let generic = matches!(
+        name,
+        "main" | "lib" | "src" | "app" | "utils" | "util" | "types" | "config"
+    );
+    (!generic && name.len() > 2).then_some(name)
+}
+
+/// The identifiers and path segments a file mentions.
+fn mentioned_words(content: &str) -> HashSet<&str> {
+    content
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+        .filter(|word| word.len() > 2)
+        .collect()
+}
+
+/// Orders the candidates by how much they tell about the project: manifests, entry
+/// points, public API, how often they change, how many files refer to them, and size.
+/// `churn` must be keyed by the same paths as the candidates, as [`file_churn`] is for the
+/// directory the files were listed in.
+///
+/// [`file_churn`]: crate::handlers::git::file_churn
+pub fn rank(candidates: &mut [Candidate], churn: &HashMap<String, usize>) {
+    let words: Vec<HashSet<&str>> = candidates
+        .iter()
+        .map(|candidate| mentioned_words(&candidate.content))
+        .collect();
+
+    let scores: HashMap<&str, f64> = candidates
+        .iter()
+        .enumerate()
+        .map(|(index, candidate)| {
+            let path = candidate.path;
+            let inbound = module_name(path).map_or(0, |name| {
+                words
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, words)| *other != index && words.contains(name))
+                    .count()
+            });
+            let changes = churn.get(path).copied().unwrap_or(0);
+            let depth = Path::new(path).components().count();
+
+            let mut score = 0.0;
+            if is_config_file(path) {
+                score += 100.0;
+            }
+            if is_entry_point(path) {
+                score += 50.0;
+            }
+            score += public_declarations(path, &candidate.content).min(20) as f64 * 1.5;
+            score += inbound.min(20) as f64 * 2.0;
+            score += (1.0 + changes as f64).ln() * 4.0;
+            score += (1.0 + candidate.content.len() as f64).ln();
+            score -= depth as f64 * 2.0;
+
+            (path, score)
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        scores[b.path]
+            .total_cmp(&scores[a.path])
+            .then_with(|| a.path.cmp(b.path))
+    });
+}
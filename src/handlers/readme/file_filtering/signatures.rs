@@ -0,0 +1,176 @@
+use std::path::Path;
+
+/// How declarations look in one family of languages.
+struct Syntax {
+    /// Prefixes of declaration lines, after indentation.
+    declarations: &'static [&'static str],
+    /// Prefixes of doc comments and attributes kept with the declaration that follows.
+    annotations: &'static [&'static str],
+    /// Deeper declarations, such as local functions, are skipped.
+    max_indent: usize,
+}
+
+const RUST: Syntax = Syntax {
+    declarations: &[
+        "pub ",
+        "pub(",
+        "fn ",
+        "async fn ",
+        "struct ",
+        "enum ",
+        "trait ",
+        "impl",
+        "mod ",
+        "type ",
+        "const ",
+        "static ",
+        "macro_rules!",
+        "//!",
+    ],
+    annotations: &["///", "#["],
+    max_indent: 4,
+};
+
+const GO: Syntax = Syntax {
+    declarations: &["package ", "func ", "type ", "const ", "var "],
+    annotations: &["//"],
+    max_indent: 0,
+};
+
+const PYTHON: Syntax = Syntax {
+    declarations: &["class ", "def ", "async def "],
+    annotations: &["@"],
+    max_indent: 4,
+};
+
+const JAVASCRIPT: Syntax = Syntax {
+    declarations: &[
+        "export ",
+        "function ",
+        "async function ",
+        "class ",
+        "interface ",
+        "type ",
+        "enum ",
+        "module.exports",
+    ],
+    annotations: &["@"],
+    max_indent: 0,
+};
+
+const JVM: Syntax = Syntax {
+    declarations: &[
+        "public ",
+        "protected ",
+        "internal ",
+        "open ",
+        "abstract ",
+        "data class ",
+        "class ",
+        "interface ",
+        "object ",
+        "enum ",
+        "fun ",
+        "func ",
+        "struct ",
+        "protocol ",
+        "extension ",
+        "namespace ",
+    ],
+    annotations: &["@", "///", "["],
+    max_indent: 4,
+};
+
+fn syntax(path: &str) -> Option<&'static Syntax> {
+    match Path::new(path).extension().and_then(|e| e.to_str())? {
+        "rs" => Some(&RUST),
+        "go" => Some(&GO),
+        "py" => Some(&PYTHON),
+        "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" => Some(&JAVASCRIPT),
+        "java" | "kt" | "kts" | "cs" | "swift" => Some(&JVM),
+        _ => None,
+    }
+}
+
+/// How many parentheses `line` leaves open, so signatures spread over several lines are
+/// kept whole.
+fn open_parens(line: &str) -> isize {
+    line.chars().fold(0, |depth, c| match c {
+        '(' => depth + 1,
+        ')' => depth - 1,
+        _ => depth,
+    })
+}
+
+/// Counts the declarations of `content` that other files can use: `pub` items in Rust,
+/// exports in JavaScript, capitalised Go identifiers and Python names without `_`.
+pub fn public_declarations(path: &str, content: &str) -> usize {
+    let ext = Path::new(path).extension().and_then(|e| e.to_str());
+    content
+        .lines()
+        .filter(|line| match ext {
+            Some("rs") => line.trim_start().starts_with("pub ") && !line.contains("pub use"),
+            Some("js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs") => line.starts_with("export "),
+            Some("go") => ["func ", "type "].iter().any(|p| {
+                line.strip_prefix(p)
+                    // Skip the receiver of methods such as `func (s *Server) Start()`.
+                    .map(|rest| match rest.strip_prefix('(') {
+                        Some(method) => method.split_once(')').map_or("", |(_, name)| name),
+                        None => rest,
+                    })
+                    .map(str::trim_start)
+                    .and_then(|rest| rest.chars().next())
+                    .is_some_and(char::is_uppercase)
+            }),
+            Some("py") => ["def ", "class "].iter().any(|p| {
+                line.strip_prefix(p)
+                    .is_some_and(|rest| !rest.starts_with('_'))
+            }),
+            Some("java" | "kt" | "kts" | "cs" | "swift") => {
+                line.trim_start().starts_with("public ")
+            }
+            _ => false,
+        })
+        .count()
+}
+
+/// Extracts the declarations of a source file, with their doc comments and attributes,
+/// leaving out the bodies. Returns `None` for languages without a known syntax.
+pub fn extract(path: &str, content: &str) -> Option<String> {
+    let syntax = syntax(path)?;
+
+    let mut output = Vec::new();
+    let mut annotations: Vec<&str> = Vec::new();
+    // Open parentheses of the current signature, and the lines it may still span.
+    let mut depth = 0;
+    let mut remaining = 0;
+
+    for line in content.lines() {
+        if depth > 0 && remaining > 0 {
+            output.push(line.trim_end().trim_end_matches('{').trim_end());
+            depth += open_parens(line);
+            remaining -= 1;
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if indent > syntax.max_indent || trimmed.is_empty() {
+            annotations.clear();
+            continue;
+        }
+
+        if syntax.declarations.iter().any(|p| trimmed.starts_with(p)) {
+            output.append(&mut annotations);
+            output.push(line.trim_end().trim_end_matches('{').trim_end());
+            depth = open_parens(line);
+            remaining = 8;
+        } else if syntax.annotations.iter().any(|p| trimmed.starts_with(p)) {
+            annotations.push(line.trim_end());
+        } else {
+            annotations.clear();
+        }
+    }
+
+    (!output.is_empty()).then(|| output.join("\n"))
+}
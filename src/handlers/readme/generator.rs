@@ -1,5 +1,8 @@
 use crate::{
-    handlers::{ai, readme::prompts},
+    handlers::{
+        ai,
+        readme::{manifest, prompts, workspace},
+    },
     models::{
         cli::{CliModel, Provider},
         error::APIError,
@@ -7,20 +10,52 @@ use crate::{
         ui,
     },
 };
-use prompts::generation::README_GENERATION_PROMPT;
-use std::fs;
-use tokio_retry::{Retry, strategy::FixedInterval};
+use prompts::generation::{README_FIX_PROMPT, README_GENERATION_PROMPT};
 
-fn build_generation_prompt(
+/// Formats extracted facts as a Markdown list, or `None` when there are none.
+fn bullet_list(items: Option<&Vec<String>>) -> String {
+    match items {
+        Some(items) if !items.is_empty() => items
+            .iter()
+            .map(|s| format!("- {}", s))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => "None".to_string(),
+    }
+}
+
+/// Builds the user message describing the project, for the README or another `document`.
+pub fn build_generation_prompt(
     analysis: &ReadmeAnalysis,
     git_context: &str,
     answers: &[String],
+    document: &str,
 ) -> String {
+    let extracted = &analysis.extracted;
+    let manifest_facts = if analysis.manifests.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "# MANIFEST FACTS (authoritative, read from the project files; they override anything else):\n\n{}\n\n---\n\n",
+            manifest::describe(&analysis.manifests)
+        )
+    };
+    let workspace_packages = if analysis.members.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "# WORKSPACE PACKAGES (this is a monorepo; in a README, add a package overview table with each package's name linked to its directory, version and description):\n\n{}\n\n---\n\n",
+            workspace::describe(&analysis.members)
+        )
+    };
+
     format!(
-        r##"# EXTRACTED PROJECT DATA (use as-is, do not repeat):
+        r##"{}{}# EXTRACTED PROJECT DATA (use as-is, do not repeat):
 
 **Project**: {}
 **Type**: {}
+**Version**: {}
+**License**: {}
 **Tech Stack**: {}
 **Core Functionality**:
 {}
@@ -28,6 +63,24 @@ fn build_generation_prompt(
 **Inferred Features**:
 {}
 
+**Dependencies**:
+{}
+
+**CLI Commands** (document every one in a command reference table):
+{}
+
+**API Endpoints** (document every one in an endpoint reference table):
+{}
+
+**Public API** (document in an API reference with these signatures):
+{}
+
+**Security Measures** (summarise in security notes):
+{}
+
+**Authentication Methods**:
+{}
+
 ---
 
 # GIT METADATA (for natural reference):
@@ -40,69 +93,83 @@ fn build_generation_prompt(
 
 ---
 
-Generate a complete, production-ready README.md using the above context. Use extracted data verbatim, incorporate git metadata naturally, and fill gaps based on user responses."##,
-        analysis
-            .extracted
-            .project_name
-            .as_deref()
-            .unwrap_or("Unknown"),
-        analysis
-            .extracted
-            .project_type
-            .as_deref()
-            .unwrap_or("other"),
-        analysis.extracted.tech_stack.join(", "),
-        analysis
-            .extracted
-            .main_functionality
-            .iter()
-            .map(|s| format!("- {}", s))
-            .collect::<Vec<_>>()
-            .join("\n"),
-        analysis
-            .extracted
-            .inferred_features
-            .as_ref()
-            .map(|features| features
-                .iter()
-                .map(|s| format!("- {}", s))
-                .collect::<Vec<_>>()
-                .join("\n"))
-            .unwrap_or_else(|| "None".to_string()),
+Generate a complete, production-ready {} using the above context. Use extracted data verbatim, incorporate git metadata naturally, and fill gaps based on user responses. Skip sections whose data is None instead of inventing content."##,
+        manifest_facts,
+        workspace_packages,
+        extracted.project_name.as_deref().unwrap_or("Unknown"),
+        extracted.project_type.as_deref().unwrap_or("other"),
+        extracted.version.as_deref().unwrap_or("Unknown"),
+        extracted.license.as_deref().unwrap_or("Unknown"),
+        extracted.tech_stack.join(", "),
+        bullet_list(Some(&extracted.main_functionality)),
+        bullet_list(extracted.inferred_features.as_ref()),
+        bullet_list(extracted.dependencies.as_ref()),
+        bullet_list(extracted.cli_commands.as_ref()),
+        bullet_list(extracted.api_endpoints.as_ref()),
+        bullet_list(extracted.public_api.as_ref()),
+        bullet_list(extracted.security_analysis.as_ref()),
+        bullet_list(extracted.authentication_methods.as_ref()),
         git_context,
-        answers.join("\n\n")
+        answers.join("\n\n"),
+        document
     )
 }
 
 pub async fn generate_final_readme(
     provider: Provider,
     model: Option<CliModel>,
-    analysis: ReadmeAnalysis,
+    analysis: &ReadmeAnalysis,
     git_context: String,
     answers: Vec<String>,
-) -> Result<(), APIError> {
+) -> Result<String, APIError> {
     ui::Logger::step("Generating README with your selections...");
 
-    let context_message = build_generation_prompt(&analysis, &git_context, &answers);
+    let context_message = build_generation_prompt(analysis, &git_context, &answers, "README.md");
 
     let ai_provider = ai::create_provider(provider, model)?;
-    let attempts = 3; // TODO: Add custom attempts
 
-    let readme_content = Retry::spawn(FixedInterval::from_millis(100).take(attempts), || async {
-        ai_provider
-            .generate_content(Some(README_GENERATION_PROMPT), vec![&context_message])
-            .await
-    })
-    .await
-    .map_err(|e| APIError::new("AI provider Readme Generation", e))?;
+    let readme_content = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        README_GENERATION_PROMPT,
+        &[&context_message],
+        "AI provider Readme Generation",
+    )
+    .await?;
+
+    Ok(readme_content)
+}
 
-    let file_path = "README.md";
+/// Asks the model to correct the problems the README check found, leaving the rest as is.
+pub async fn fix_readme(
+    provider: Provider,
+    model: Option<CliModel>,
+    analysis: &ReadmeAnalysis,
+    readme: &str,
+    issues: &[String],
+) -> Result<String, APIError> {
+    ui::Logger::step("Asking the model to fix the README problems...");
+
+    let context_message = format!(
+        "# MANIFEST FACTS:\n\n{}\n\n**CLI Commands**:\n{}\n\n---\n\n# PROBLEMS:\n{}\n\n---\n\n# README:\n\n{}",
+        manifest::describe(&analysis.manifests),
+        bullet_list(analysis.extracted.cli_commands.as_ref()),
+        issues
+            .iter()
+            .map(|issue| format!("- {}", issue))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        readme
+    );
 
-    ui::Logger::step("Writing README.md...");
-    fs::write(file_path, readme_content).map_err(|e| APIError::new("fs::write", e))?;
+    let ai_provider = ai::create_provider(provider, model)?;
 
-    ui::Logger::done("README.md successfully generated!");
-    ui::Logger::kv("Location", file_path);
+    let readme_content = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        README_FIX_PROMPT,
+        &[&context_message],
+        "AI provider Readme Fix",
+    )
+    .await?;
 
-    Ok(())
+    Ok(readme_content)
 }
@@ -0,0 +1,388 @@
+use crate::models::readme::{ExtractedData, ManifestFacts};
+use serde_json::Value as JsonValue;
+use std::{fs, path::Path};
+use toml::{Table, Value};
+
+const MANIFESTS: [&str; 4] = ["Cargo.toml", "package.json", "pyproject.toml", "go.mod"];
+
+fn toml_str(table: &Table, key: &str) -> Option<String> {
+    table.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+fn toml_keys(table: &Table, key: &str) -> Vec<String> {
+    table
+        .get(key)
+        .and_then(Value::as_table)
+        .map(|deps| deps.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Reads `[package]`, `[dependencies]` and `[[bin]]`. A package with `src/main.rs` also
+/// gets its default binary, named after the package.
+fn parse_cargo(content: &str, has_main: bool) -> Option<ManifestFacts> {
+    let manifest: Table = content.parse().ok()?;
+    let package = manifest.get("package")?.as_table()?;
+
+    let name = toml_str(package, "name");
+    let mut binaries: Vec<String> = manifest
+        .get("bin")
+        .and_then(Value::as_array)
+        .map(|bins| {
+            bins.iter()
+                .filter_map(|bin| bin.get("name").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    if has_main
+        && let Some(name) = &name
+        && !binaries.contains(name)
+    {
+        binaries.push(name.clone());
+    }
+
+    Some(ManifestFacts {
+        name,
+        // `version.workspace = true` is a table and is left to the workspace manifest.
+        version: toml_str(package, "version"),
+        license: toml_str(package, "license"),
+        description: toml_str(package, "description"),
+        dependencies: toml_keys(&manifest, "dependencies"),
+        binaries,
+        // `publish = false` or a list without crates.io keeps it off crates.io.
+        published: match package.get("publish") {
+            None | Some(Value::Boolean(true)) => true,
+            Some(Value::Array(registries)) => registries
+                .iter()
+                .any(|registry| registry.as_str() == Some("crates-io")),
+            _ => false,
+        } && package.contains_key("repository"),
+        ..Default::default()
+    })
+}
+
+fn parse_package_json(content: &str) -> Option<ManifestFacts> {
+    let manifest: JsonValue = serde_json::from_str(content).ok()?;
+    let string = |key: &str| {
+        manifest
+            .get(key)
+            .and_then(JsonValue::as_str)
+            .map(str::to_string)
+    };
+
+    let license = string("license").or_else(|| {
+        manifest
+            .pointer("/license/type")
+            .and_then(JsonValue::as_str)
+            .map(str::to_string)
+    });
+    let binaries = match manifest.get("bin") {
+        Some(JsonValue::String(_)) => string("name")
+            .map(|name| name.rsplit('/').next().unwrap_or(&name).to_string())
+            .into_iter()
+            .collect(),
+        Some(JsonValue::Object(bins)) => bins.keys().cloned().collect(),
+        _ => Vec::new(),
+    };
+
+    Some(ManifestFacts {
+        name: string("name"),
+        version: string("version"),
+        license,
+        description: string("description"),
+        dependencies: manifest
+            .get("dependencies")
+            .and_then(JsonValue::as_object)
+            .map(|deps| deps.keys().cloned().collect())
+            .unwrap_or_default(),
+        binaries,
+        published: !manifest
+            .get("private")
+            .and_then(JsonValue::as_bool)
+            .unwrap_or(false)
+            && (manifest.get("repository").is_some() || manifest.get("publishConfig").is_some()),
+        ..Default::default()
+    })
+}
+
+/// The distribution name of a PEP 508 requirement such as `requests[socks]>=2.31`.
+fn requirement_name(requirement: &str) -> String {
+    requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect()
+}
+
+/// Reads PEP 621 `[project]` metadata, falling back to `[tool.poetry]`.
+fn parse_pyproject(content: &str) -> Option<ManifestFacts> {
+    let manifest: Table = content.parse().ok()?;
+
+    if let Some(project) = manifest.get("project").and_then(Value::as_table) {
+        let license = match project.get("license") {
+            Some(Value::String(license)) => Some(license.clone()),
+            Some(Value::Table(license)) => toml_str(license, "text"),
+            _ => None,
+        };
+
+        return Some(ManifestFacts {
+            name: toml_str(project, "name"),
+            version: toml_str(project, "version"),
+            license,
+            description: toml_str(project, "description"),
+            dependencies: project
+                .get("dependencies")
+                .and_then(Value::as_array)
+                .map(|deps| {
+                    deps.iter()
+                        .filter_map(Value::as_str)
+                        .map(requirement_name)
+                        .filter(|name| !name.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            binaries: toml_keys(project, "scripts"),
+            published: project.contains_key("urls")
+                && !project
+                    .get("classifiers")
+                    .and_then(Value::as_array)
+                    .is_some_and(|classifiers| {
+                        classifiers
+                            .iter()
+                            .any(|c| c.as_str() == Some("Private :: Do Not Upload"))
+                    }),
+            ..Default::default()
+        });
+    }
+
+    let poetry = manifest.get("tool")?.get("poetry")?.as_table()?;
+    Some(ManifestFacts {
+        name: toml_str(poetry, "name"),
+        version: toml_str(poetry, "version"),
+        license: toml_str(poetry, "license"),
+        description: toml_str(poetry, "description"),
+        dependencies: toml_keys(poetry, "dependencies")
+            .into_iter()
+            .filter(|name| name != "python")
+            .collect(),
+        binaries: toml_keys(poetry, "scripts"),
+        published: poetry.contains_key("repository"),
+        ..Default::default()
+    })
+}
+
+/// Reads the module path and the direct requirements of a `go.mod`.
+fn parse_go_mod(content: &str) -> Option<ManifestFacts> {
+    let mut facts = ManifestFacts::default();
+    let mut in_require = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if in_require {
+            if line == ")" {
+                in_require = false;
+            } else if !line.is_empty() && !line.contains("// indirect") {
+                facts
+                    .dependencies
+                    .extend(line.split_whitespace().next().map(str::to_string));
+            }
+        } else if let Some(module) = line.strip_prefix("module ") {
+            facts.name = Some(module.trim().trim_matches('"').to_string());
+        } else if line == "require (" {
+            in_require = true;
+        } else if let Some(require) = line.strip_prefix("require ")
+            && !require.contains("// indirect")
+        {
+            facts
+                .dependencies
+                .extend(require.split_whitespace().next().map(str::to_string));
+        }
+    }
+
+    // Modules are fetched from their host, so any module path under one is on pkg.go.dev.
+    facts.published = facts.name.as_deref().is_some_and(|name| {
+        name.split('/')
+            .next()
+            .is_some_and(|host| host.contains('.'))
+    });
+    facts.name.is_some().then_some(facts)
+}
+
+/// Parses the manifests directly inside `root` among `files`, which are relative to it.
+pub fn read_manifests(root: &Path, files: &[&str]) -> Vec<ManifestFacts> {
+    read_manifests_in(root, "", files)
+}
+
+/// Parses the manifests directly inside `dir`, a directory relative to `root` (`""` for
+/// `root` itself), among `files`.
+pub fn read_manifests_in(root: &Path, dir: &str, files: &[&str]) -> Vec<ManifestFacts> {
+    let in_dir = |name: &str| {
+        Path::new(dir)
+            .join(name)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+    let has_main = files.contains(&in_dir("src/main.rs").as_str());
+
+    MANIFESTS
+        .iter()
+        .map(|&manifest| (manifest, in_dir(manifest)))
+        .filter(|(_, path)| files.contains(&path.as_str()))
+        .filter_map(|(manifest, path)| {
+            let content = fs::read_to_string(root.join(&path)).ok()?;
+            let facts = match manifest {
+                "Cargo.toml" => parse_cargo(&content, has_main),
+                "package.json" => parse_package_json(&content),
+                "pyproject.toml" => parse_pyproject(&content),
+                _ => parse_go_mod(&content),
+            };
+            facts.map(|facts| ManifestFacts {
+                source: path,
+                ..facts
+            })
+        })
+        .collect()
+}
+
+/// Describes the manifest facts for the prompts.
+pub fn describe(manifests: &[ManifestFacts]) -> String {
+    manifests
+        .iter()
+        .map(|facts| {
+            let list = |items: &[String]| {
+                if items.is_empty() {
+                    "None".to_string()
+                } else {
+                    items.join(", ")
+                }
+            };
+            format!(
+                "## {}\n- Name: {}\n- Version: {}\n- License: {}\n- Dependencies: {}\n- Binaries: {}",
+                facts.source,
+                facts.name.as_deref().unwrap_or("Unknown"),
+                facts.version.as_deref().unwrap_or("Unknown"),
+                facts.license.as_deref().unwrap_or("Unknown"),
+                list(&facts.dependencies),
+                list(&facts.binaries)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn normalize(value: &str) -> String {
+    value
+        .trim()
+        .trim_start_matches('v')
+        .to_lowercase()
+        .replace('_', "-")
+}
+
+/// Overwrites `field` with the manifest value, reporting when the model disagreed.
+fn apply_fact(
+    field: &mut Option<String>,
+    fact: Option<(&String, &str)>,
+    label: &str,
+    disagreements: &mut Vec<String>,
+) {
+    let Some((fact, source)) = fact else {
+        return;
+    };
+    if let Some(current) = field.as_deref()
+        && normalize(current) != normalize(fact)
+    {
+        disagreements.push(format!(
+            "{}: the analysis found '{}' but {} says '{}'",
+            label, current, source, fact
+        ));
+    }
+    *field = Some(fact.clone());
+}
+
+/// Pre-fills the extracted data with the manifest facts, which take precedence over the
+/// model's analysis. Returns the facts the model disagreed on.
+pub fn apply(extracted: &mut ExtractedData, manifests: &[ManifestFacts]) -> Vec<String> {
+    // The first manifest with a value wins, in the order of `MANIFESTS`.
+    let first = |get: fn(&ManifestFacts) -> Option<&String>| {
+        manifests
+            .iter()
+            .find_map(|facts| get(facts).map(|value| (value, facts.source.as_str())))
+    };
+
+    let mut disagreements = Vec::new();
+    apply_fact(
+        &mut extracted.project_name,
+        first(|facts| facts.name.as_ref()),
+        "Project name",
+        &mut disagreements,
+    );
+    apply_fact(
+        &mut extracted.version,
+        first(|facts| facts.version.as_ref()),
+        "Version",
+        &mut disagreements,
+    );
+    apply_fact(
+        &mut extracted.license,
+        first(|facts| facts.license.as_ref()),
+        "License",
+        &mut disagreements,
+    );
+
+    let dependencies: Vec<String> = manifests
+        .iter()
+        .flat_map(|facts| facts.dependencies.iter().cloned())
+        .collect();
+    if !dependencies.is_empty() {
+        extracted.dependencies = Some(dependencies);
+    }
+
+    disagreements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_cargo_packages() {
+        let facts = parse_cargo(
+            "[package]\nname = \"app\"\nversion = \"1.2.0\"\nrepository = \"https://example.com/app\"\n\n[dependencies]\nserde = \"1\"\n\n[[bin]]\nname = \"app-cli\"\n",
+            true,
+        )
+        .unwrap();
+        assert_eq!(facts.name.as_deref(), Some("app"));
+        assert_eq!(facts.version.as_deref(), Some("1.2.0"));
+        assert_eq!(facts.dependencies, ["serde"]);
+        assert_eq!(facts.binaries, ["app-cli", "app"]);
+        assert!(facts.published);
+
+        let internal = parse_cargo(
+            "[package]\nname = \"app\"\nrepository = \"x\"\npublish = false\n",
+            false,
+        )
+        .unwrap();
+        assert!(!internal.published);
+    }
+
+    #[test]
+    fn reads_package_json() {
+        let facts = parse_package_json(
+            r#"{"name": "@acme/cli", "version": "2.0.0", "license": {"type": "MIT"}, "bin": "cli.js", "private": true}"#,
+        )
+        .unwrap();
+        assert_eq!(facts.license.as_deref(), Some("MIT"));
+        assert_eq!(facts.binaries, ["cli"]);
+        assert!(!facts.published);
+    }
+
+    #[test]
+    fn reads_go_modules() {
+        let facts =
+            parse_go_mod("module github.com/acme/tool\n\nrequire (\n\tgithub.com/spf13/cobra v1.8.0\n\tgolang.org/x/sys v0.1.0 // indirect\n)\n")
+                .unwrap();
+        assert_eq!(facts.name.as_deref(), Some("github.com/acme/tool"));
+        assert_eq!(facts.dependencies, ["github.com/spf13/cobra"]);
+        assert!(facts.published);
+    }
+}
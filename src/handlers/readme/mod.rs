@@ -1,23 +1,201 @@
 mod analysis;
+mod answers;
+mod badges;
+mod diagram;
+mod docs;
 mod file_filtering;
 mod generator;
+mod manifest;
 mod prompts;
+mod sections;
+mod update;
+mod validate;
+mod workspace;
+mod writer;
 
 use crate::models::{
     cli::{CliModel, Provider},
     error::APIError,
+    readme::DocKind,
     ui,
 };
 use analysis::analyze_readme_content;
-use generator::generate_final_readme;
+use docs::generate_document;
+use generator::{fix_readme, generate_final_readme};
+use std::{fs, path::PathBuf};
+use update::update_existing_readme;
 
-pub async fn handle_readme(provider: Provider, model: Option<CliModel>) -> Result<(), APIError> {
+pub use answers::AnswerOptions;
+
+const README_PATH: &str = "README.md";
+
+/// Options for generating or updating the README.
+pub struct ReadmeOptions {
+    /// Update the existing README section by section instead of replacing it.
+    pub update: bool,
+    /// Show the changes as a diff and ask before writing them.
+    pub preview: bool,
+    /// Add an architecture section with a Mermaid diagram of the module graph.
+    pub diagram: bool,
+    /// Generate the README of this workspace package, by name or path.
+    pub package: Option<String>,
+    pub answers: AnswerOptions,
+}
+
+pub async fn handle_readme(
+    options: ReadmeOptions,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
     ui::Logger::header("README GENERATOR");
 
+    let root = match &options.package {
+        Some(package) => workspace::package_root(package)?,
+        None => PathBuf::new(),
+    };
+    let readme_path = root.join(README_PATH);
+    let readme_path_str = readme_path.to_string_lossy();
+
+    if options.update && !readme_path.exists() {
+        ui::Logger::warning("No README.md to update, a new one will be generated.");
+    }
+    let update = options.update && readme_path.exists();
+    let preview = options.preview;
+
     let (analysis, git_context, answers) =
-        analyze_readme_content(provider.clone(), model.clone()).await?;
+        analyze_readme_content(&root, provider.clone(), model.clone(), &options.answers).await?;
+
+    let badges = badges::compute(&root, &analysis.manifests);
+    let architecture = if options.diagram {
+        diagram::generate(&root, provider.clone(), model.clone()).await?
+    } else {
+        None
+    };
+
+    if update {
+        let updated = update_existing_readme(
+            &readme_path_str,
+            provider,
+            model,
+            &analysis,
+            git_context,
+            answers,
+        )
+        .await?;
+        // A new diagram is worth writing even when no section went stale.
+        let updated = match (updated, &architecture) {
+            (None, Some(_)) => {
+                Some(fs::read_to_string(&readme_path).map_err(|e| APIError::new("fs::read", e))?)
+            }
+            (updated, _) => updated,
+        };
+
+        if let Some(mut content) = updated {
+            if let Some(architecture) = &architecture {
+                content = diagram::insert(&content, architecture);
+            }
+            // Kept sections must stay verbatim, so problems are only reported here.
+            let findings = validate::check(&root, &content, &analysis.manifests)?;
+            if !findings.is_empty() {
+                validate::report(&findings);
+            }
+            let content = badges::inject(&content, &badges)?;
+            writer::write_readme(&readme_path_str, &content, preview, "updated")?;
+        }
+    } else {
+        let mut content = generate_final_readme(
+            provider.clone(),
+            model.clone(),
+            &analysis,
+            git_context,
+            answers,
+        )
+        .await?;
+
+        let findings = validate::check(&root, &content, &analysis.manifests)?;
+        if !findings.is_empty() {
+            validate::report(&findings);
+        }
+        // Install command notes alone are not worth a rewrite, they may be deliberate.
+        if !findings.fixable.is_empty() {
+            content = fix_readme(provider, model, &analysis, &content, &findings.fixable).await?;
+
+            let remaining = validate::check(&root, &content, &analysis.manifests)?;
+            if remaining.is_empty() {
+                ui::Logger::success("All README problems fixed");
+            } else {
+                validate::report(&remaining);
+            }
+        }
+
+        if let Some(architecture) = &architecture {
+            content = diagram::insert(&content, architecture);
+        }
+        let content = badges::inject(&content, &badges)?;
+        writer::write_readme(&readme_path_str, &content, preview, "generated")?;
+    }
+
+    Ok(())
+}
+
+/// Generates another repository document, such as `CONTRIBUTING.md`, from the same
+/// repository analysis as the README.
+pub async fn handle_docs(
+    kind: DocKind,
+    output: Option<&str>,
+    package: Option<&str>,
+    answer_options: AnswerOptions,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header(&format!("{} GENERATOR", kind.title().to_uppercase()));
+
+    let root = match package {
+        Some(package) => workspace::package_root(package)?,
+        None => PathBuf::new(),
+    };
+
+    let (analysis, git_context, answers) =
+        analyze_readme_content(&root, provider.clone(), model.clone(), &answer_options).await?;
+
+    // An explicit output is relative to where the command runs, the default to the package.
+    let path = match output {
+        Some(output) => PathBuf::from(output),
+        None => root.join(kind.default_path()),
+    };
+
+    generate_document(kind, &path, provider, model, analysis, git_context, answers).await?;
+
+    Ok(())
+}
+
+/// Draws the architecture of the repository as a Mermaid flowchart, from the source
+/// directories and their imports.
+pub async fn handle_diagram(
+    output: Option<&str>,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("ARCHITECTURE DIAGRAM");
+
+    let Some(architecture) = diagram::generate(&PathBuf::new(), provider, model).await? else {
+        return Ok(());
+    };
+
+    match output {
+        Some(file_path) => {
+            ui::Logger::step(&format!("Writing {}...", file_path));
+            fs::write(file_path, format!("{}\n", architecture))
+                .map_err(|e| APIError::new("fs::write", e))?;
 
-    generate_final_readme(provider, model, analysis, git_context, answers).await?;
+            ui::Logger::done("Architecture diagram successfully generated!");
+            ui::Logger::kv("Location", file_path);
+        }
+        None => {
+            eprintln!();
+            println!("{}", architecture);
+        }
+    }
 
     Ok(())
 }
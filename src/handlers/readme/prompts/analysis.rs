@@ -65,4 +65,12 @@ CRITICAL RULES:
 5. "extracted.main_functionality" should be concise bullet points of what the code actually does
 "#;
 
-pub const README_ANALYSIS_USER_PROMPT: &str = r#"Analyze this codebase. Extract as much info as possible to make the most comprehensive analysis, then ask ONLY essential questions about information you cannot infer from the code."#;
\ No newline at end of file
+pub const README_ANALYSIS_USER_PROMPT: &str = r#"Analyze this codebase. Extract as much info as possible to make the most comprehensive analysis, then ask ONLY essential questions about information you cannot infer from the code."#;
+
+pub const README_MANIFEST_FACTS_PROMPT: &str = r#"The facts below were read directly from the project manifests. They are AUTHORITATIVE: copy the name, version, license and dependencies into "extracted" exactly as given, and never contradict them. Binaries are the commands the project installs.
+
+{}"#;
+
+pub const README_WORKSPACE_PROMPT: &str = r#"This repository is a workspace (monorepo) with the packages below. Describe the workspace as a whole: "main_functionality" covers what the packages do together, and the file contents are grouped by package.
+
+{}"#;
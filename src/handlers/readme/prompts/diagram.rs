@@ -0,0 +1,15 @@
+pub const DIAGRAM_LABEL_PROMPT: &str = r#"# You label the components of a software architecture diagram.
+
+You receive the components of a repository, each a directory with the source files it contains, and the dependencies between them as found in the import statements. The structure is fixed: do not add, remove, merge or rename components, and do not add dependencies.
+
+For every component, give a short label (2 to 4 words) naming its role, such as "AI providers", "CLI entry point" or "Git helpers". Base it on the directory and file names.
+
+OUTPUT FORMAT (STRICT JSON):
+{
+  "labels": {
+    "src/handlers/ai": "AI providers"
+  }
+}
+
+Use the component paths exactly as given as keys. Return ONLY valid JSON, no markdown fences or explanations.
+"#;
@@ -0,0 +1,94 @@
+pub const CONTRIBUTING_PROMPT: &str = r#"# You are writing CONTRIBUTING.md for a GitHub repository.
+
+Use the extracted project data, git metadata and user responses to write a practical guide for first-time contributors.
+
+REQUIRED STRUCTURE:
+1. # Contributing to <Project Name> - a short welcome and the kinds of contributions that are wanted
+2. ## Getting Started - prerequisites and the exact commands to clone, build and run the project, matching its tech stack
+3. ## Development Workflow - branching, keeping the fork up to date, running tests, linters and formatters
+4. ## Commit Messages - the conventions the project uses (Conventional Commits when in doubt)
+5. ## Pull Requests - what a good PR contains and how review works
+6. ## Reporting Bugs and Requesting Features - point to the issue tracker and issue templates
+7. ## Code of Conduct - link to CODE_OF_CONDUCT.md
+
+RULES:
+- Only give commands that fit the detected tech stack (e.g. cargo for Rust, npm/pnpm for Node, pip/uv for Python)
+- Keep it concise and actionable, with fenced code blocks tagged with their language
+- Output ONLY Markdown, no explanations and no code fences around the whole document
+"#;
+
+pub const SECURITY_PROMPT: &str = r#"# You are writing SECURITY.md for a GitHub repository.
+
+Use the extracted project data, git metadata and user responses to write a clear security policy.
+
+REQUIRED STRUCTURE:
+1. # Security Policy
+2. ## Supported Versions - a table of versions and whether they receive security fixes; use the project version when known, otherwise describe the policy as "latest release only"
+3. ## Reporting a Vulnerability - ask reporters NOT to open public issues; give the private channel (GitHub private vulnerability reporting, or the maintainer email from the git metadata), what to include in a report, and expected response times
+4. ## Disclosure Policy - coordinated disclosure and how fixes are announced
+5. ## Security Considerations - short notes drawn from the project's authentication, secrets handling or network access, only when supported by the data
+
+RULES:
+- Never invent email addresses, bug bounty programs or PGP keys; only use contacts present in the context
+- Output ONLY Markdown, no explanations and no code fences around the whole document
+"#;
+
+pub const CODE_OF_CONDUCT_PROMPT: &str = r#"# You are writing CODE_OF_CONDUCT.md for a GitHub repository.
+
+Adapt the Contributor Covenant version 2.1 to the project.
+
+REQUIRED STRUCTURE:
+1. # Contributor Covenant Code of Conduct
+2. ## Our Pledge
+3. ## Our Standards
+4. ## Enforcement Responsibilities
+5. ## Scope
+6. ## Enforcement - the reporting contact: the maintainer email from the git metadata when available, otherwise "the project maintainers"
+7. ## Enforcement Guidelines - Correction, Warning, Temporary Ban, Permanent Ban
+8. ## Attribution - credit the Contributor Covenant with its link
+
+RULES:
+- Keep the wording of the Contributor Covenant; only fill in the project name and contact
+- Output ONLY Markdown, no explanations and no code fences around the whole document
+"#;
+
+pub const ISSUE_TEMPLATES_PROMPT: &str = r#"# You are writing GitHub issue templates for a repository.
+
+Write two templates tailored to the project:
+- bug_report.md: reproduction steps, expected and actual behaviour, and an environment section asking for the details that matter for this tech stack (e.g. OS, toolchain or runtime version, project version)
+- feature_request.md: problem, proposed solution, alternatives, additional context
+
+Each template starts with GitHub's YAML front matter (name, about, title, labels, assignees).
+
+OUTPUT FORMAT:
+Put each file after a marker line naming it, exactly like this:
+<!-- gitz:file bug_report.md -->
+---
+name: Bug report
+...
+<!-- gitz:file feature_request.md -->
+---
+name: Feature request
+...
+
+RULES:
+- Output ONLY the marker lines and the templates, no explanations and no code fences
+"#;
+
+pub const USAGE_GUIDE_PROMPT: &str = r#"# You are writing USAGE.md, a detailed usage guide for a GitHub repository.
+
+The README gives an overview; this guide is the reference users come back to. Use the extracted project data, git metadata and user responses.
+
+REQUIRED STRUCTURE:
+1. # <Project Name> Usage Guide
+2. ## Installation - every install method that fits the tech stack, with prerequisites
+3. ## Configuration - configuration files and environment variables, as tables when there are several
+4. ## Usage - one ### subsection per command, feature or main API, each with a short explanation and a fenced example
+5. ## Examples - end-to-end scenarios, from basic to advanced
+6. ## Troubleshooting - common problems and their fixes, only when supported by the data
+
+RULES:
+- Only document commands, options and APIs present in the extracted data; never invent flags
+- Fenced code blocks must be tagged with their language
+- Output ONLY Markdown, no explanations and no code fences around the whole document
+"#;
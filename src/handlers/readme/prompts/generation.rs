@@ -8,7 +8,7 @@ CONTEXT USAGE:
 
 VISUAL DESIGN (Modern GitHub Style):
 - Strategic emoji for section headers: ✨🚀📦⚡🎯🔧📚🤝📝⚠️
-- Shields.io badges: ![Build](https://img.shields.io/badge/build-passing-brightgreen)
+- NO badges: gitz adds verified badges under the title itself
 - Syntax-highlighted code blocks with language tags
 - Tables for structured data (commands, config options, API reference)
 - Horizontal rules (---) between major sections
@@ -22,8 +22,6 @@ REQUIRED STRUCTURE (adapt to project type):
    # 🚀 Project Name
    > *Catchy one-liner that explains value proposition*
 
-   ![Build](badge) ![Version](badge) ![License](badge) ![Language](badge)
-
    [Screenshot/Demo/ASCII art based on project type]
    ```
 
@@ -75,20 +73,21 @@ REQUIRED STRUCTURE (adapt to project type):
     - License type
     - Copyright holder
 
+DEDICATED SECTIONS FROM EXTRACTED DATA:
+- **CLI Commands** → in 💻 Usage, a command reference table (| Command | Description | Example |) listing every extracted command
+- **API Endpoints** → a 🌐 API Reference table (| Method | Endpoint | Description |) listing every extracted endpoint, plus a request/response example when the authentication method is known
+- **Public API** → in 📚 API Reference, the extracted signatures in ```language blocks, each with a one-line description
+- **Security Measures** / **Authentication Methods** → a 🔒 Security section with short notes on how authentication works, what is validated, and how to report vulnerabilities
+- **Version** / **License** → use them exactly in installation commands and the 📝 License section
+- **Dependencies** → mention notable runtime requirements in Prerequisites; do not list every dependency
+- Omit a dedicated section entirely when its data is None
+
 PROJECT TYPE ADAPTATIONS:
 - **CLI**: Emphasize commands, options, usage examples
 - **Library**: Focus on API reference, integration examples, types
 - **Web App**: Screenshots, features, deployment guide
 - **API**: Endpoints, request/response examples, authentication
 
-BADGE TEMPLATES (replace placeholders):
-```markdown
-![Build](https://img.shields.io/github/actions/workflow/status/USER/REPO/ci.yml?style=flat-square)
-![Version](https://img.shields.io/crates/v/CRATE?style=flat-square)
-![License](https://img.shields.io/badge/license-MIT-blue?style=flat-square)
-![Language](https://img.shields.io/badge/rust-1.70+-orange?style=flat-square)
-```
-
 CODE BLOCK RULES:
 - Always specify language: ```rust, ```bash, ```json
 - Add inline comments for clarity
@@ -111,7 +110,7 @@ TONE:
 CRITICAL OUTPUT RULES:
 1. Output ONLY Markdown - no explanations, no meta-commentary
 2. Complete, copy-paste ready content
-3. Replace ALL placeholders (USER, REPO, CRATE) with actual values
+3. Replace ALL placeholders with actual values
 4. Consistent formatting and spacing
 5. Add table of contents for READMEs > 200 lines
 6. All code blocks must be valid and properly formatted
@@ -122,5 +121,18 @@ WHAT NOT TO DO:
 ❌ Don't add "based on analysis" or similar meta-statements
 ❌ Don't include JSON or code analysis in output
 ❌ Don't use placeholder values if real ones are available
+❌ Don't add shields.io or any other badges
 ❌ Don't add your own assumptions beyond provided data
-"#;
\ No newline at end of file
+"#;
+
+pub const README_FIX_PROMPT: &str = r#"# You are a GitHub README editor. Fix the problems a checker found in a generated README.md.
+
+You receive the README and a list of problems. Each problem is a link to a file that does not exist or a command-line flag the project does not define.
+
+RULES:
+1. Fix every listed problem: use the flags and files from the project data, or remove the line if there is no correct equivalent.
+2. Change nothing else: keep all other text, sections, formatting and emoji exactly as they are.
+3. Never invent new flags or files.
+
+Output ONLY the complete corrected Markdown - no explanations, no meta-commentary, no surrounding code fence.
+"#;
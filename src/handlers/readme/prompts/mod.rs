@@ -1,2 +1,5 @@
 pub mod analysis;
+pub mod diagram;
+pub mod docs;
 pub mod generation;
+pub mod update;
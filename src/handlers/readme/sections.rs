@@ -0,0 +1,103 @@
+/// Marks a section the update mode must leave untouched.
+pub const KEEP_MARKER: &str = "<!-- gitz:keep -->";
+/// Marks a section the update mode must always regenerate.
+pub const REGENERATE_MARKER: &str = "<!-- gitz:regenerate -->";
+
+/// A README section: a level 1 or 2 heading and everything up to the next one. The text
+/// before the first heading is a section without a heading.
+pub struct Section {
+    pub heading: Option<String>,
+    /// Raw Markdown of the section, including the heading line.
+    pub content: String,
+}
+
+impl Section {
+    pub fn is_kept(&self) -> bool {
+        self.content.contains(KEEP_MARKER)
+    }
+
+    pub fn is_marked_for_regeneration(&self) -> bool {
+        self.content.contains(REGENERATE_MARKER)
+    }
+}
+
+fn section_heading(line: &str) -> Option<String> {
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    let title = line[hashes..].strip_prefix(' ')?;
+    (1..=2).contains(&hashes).then(|| title.trim().to_string())
+}
+
+/// Splits a README at its level 1 and 2 headings, ignoring lines inside code fences.
+pub fn parse(markdown: &str) -> Vec<Section> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut in_fence = false;
+
+    for line in markdown.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+
+        let heading = if in_fence {
+            None
+        } else {
+            section_heading(line.trim_end())
+        };
+
+        match (heading, sections.last_mut()) {
+            (None, Some(section)) => section.content.push_str(line),
+            (heading, _) => sections.push(Section {
+                heading,
+                content: line.to_string(),
+            }),
+        }
+    }
+
+    sections
+}
+
+/// Returns `content` with the regeneration marker placed under its heading line, so a
+/// section keeps being regenerated on later updates.
+pub fn with_regenerate_marker(content: &str) -> String {
+    if content.contains(REGENERATE_MARKER) {
+        return content.to_string();
+    }
+
+    match content.split_once('\n') {
+        Some((heading, rest)) if section_heading(heading).is_some() => {
+            format!("{}\n{}\n{}", heading, REGENERATE_MARKER, rest)
+        }
+        _ => format!("{}\n{}", REGENERATE_MARKER, content),
+    }
+}
+
+/// Ends a section with a blank line so that merged sections stay separated.
+pub fn as_block(content: &str) -> String {
+    format!("{}\n\n", content.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_at_level_one_and_two_headings_outside_fences() {
+        let readme = "intro\n# Title\ntext\n### Detail\n```sh\n# comment\n## not a heading\n```\n## Usage\n<!-- gitz:keep -->\nrun it\n";
+        let sections = parse(readme);
+
+        let headings: Vec<Option<&str>> = sections
+            .iter()
+            .map(|section| section.heading.as_deref())
+            .collect();
+        assert_eq!(headings, [None, Some("Title"), Some("Usage")]);
+        assert!(sections[1].content.contains("## not a heading"));
+        assert!(sections[2].is_kept());
+        assert_eq!(
+            sections
+                .iter()
+                .map(|s| s.content.as_str())
+                .collect::<String>(),
+            readme
+        );
+    }
+}
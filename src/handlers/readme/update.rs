@@ -0,0 +1,150 @@
+use crate::{
+    handlers::{
+        ai, json,
+        readme::{prompts, sections},
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        readme::{ReadmeAnalysis, ReadmeUpdate},
+        ui,
+    },
+};
+use sections::{Section, as_block};
+use std::{collections::HashMap, fs};
+
+use super::generator::build_generation_prompt;
+
+fn section_flag(section: &Section) -> &'static str {
+    if section.is_kept() {
+        "KEEP"
+    } else if section.is_marked_for_regeneration() {
+        "REGENERATE"
+    } else {
+        "CURRENT"
+    }
+}
+
+fn describe_sections(sections: &[Section]) -> String {
+    sections
+        .iter()
+        .enumerate()
+        .map(|(i, section)| {
+            let body = if section.is_kept() {
+                "(kept verbatim, content omitted)\n"
+            } else {
+                section.content.as_str()
+            };
+            format!("## SECTION {} [{}]\n{}", i, section_flag(section), body)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Applies the model's updates and additions, never touching kept sections.
+fn merge_sections(sections: &[Section], update: ReadmeUpdate) -> String {
+    let mut updates: HashMap<usize, String> = update
+        .updates
+        .into_iter()
+        .map(|u| (u.index, u.content))
+        .collect();
+    let mut additions: HashMap<usize, Vec<String>> = HashMap::new();
+    for addition in update.additions {
+        let after = addition.after.min(sections.len().saturating_sub(1));
+        additions.entry(after).or_default().push(addition.content);
+    }
+
+    let mut merged = String::new();
+    for (i, section) in sections.iter().enumerate() {
+        match updates.remove(&i) {
+            Some(_) if section.is_kept() => {
+                ui::Logger::warning(&format!(
+                    "Ignored a change to the kept section {}",
+                    section.heading.as_deref().unwrap_or("(intro)")
+                ));
+                merged.push_str(&section.content);
+            }
+            Some(content) => {
+                ui::Logger::item(&format!(
+                    "Updated {}",
+                    section.heading.as_deref().unwrap_or("(intro)")
+                ));
+                if section.is_marked_for_regeneration() {
+                    merged.push_str(&as_block(&sections::with_regenerate_marker(&content)));
+                } else {
+                    merged.push_str(&as_block(&content));
+                }
+            }
+            None => {
+                if section.is_marked_for_regeneration() {
+                    ui::Logger::warning(&format!(
+                        "{} is marked for regeneration but was not updated",
+                        section.heading.as_deref().unwrap_or("(intro)")
+                    ));
+                }
+                merged.push_str(&section.content);
+            }
+        }
+
+        for content in additions.remove(&i).unwrap_or_default() {
+            while !merged.ends_with("\n\n") {
+                merged.push('\n');
+            }
+            ui::Logger::item(&format!(
+                "Added {}",
+                content.lines().next().unwrap_or_default().trim()
+            ));
+            merged.push_str(&as_block(&content));
+        }
+    }
+
+    format!("{}\n", merged.trim_end())
+}
+
+/// Updates `file_path` section by section: kept sections stay verbatim, and only sections
+/// marked for regeneration or found stale are rewritten. Returns `None` when nothing changed.
+pub async fn update_existing_readme(
+    file_path: &str,
+    provider: Provider,
+    model: Option<CliModel>,
+    analysis: &ReadmeAnalysis,
+    git_context: String,
+    answers: Vec<String>,
+) -> Result<Option<String>, APIError> {
+    let existing = fs::read_to_string(file_path).map_err(|e| APIError::new("fs::read", e))?;
+    let sections = sections::parse(&existing);
+
+    let kept = sections.iter().filter(|s| s.is_kept()).count();
+    ui::Logger::step(&format!(
+        "Updating {} ({} section(s), {} kept)...",
+        file_path,
+        sections.len(),
+        kept
+    ));
+
+    let context_message = format!(
+        "{}\n\n---\n\n# EXISTING README SECTIONS:\n\n{}\n---\n\nReturn the updated and added sections as JSON.",
+        build_generation_prompt(analysis, &git_context, &answers, file_path),
+        describe_sections(&sections)
+    );
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::update::README_UPDATE_PROMPT,
+        &[&context_message],
+        "AI provider Readme Update",
+    )
+    .await?;
+
+    let update: ReadmeUpdate = serde_json::from_str(&json::handle_json_strip(&response))
+        .map_err(|e| APIError::new("Invalid README update JSON", e))?;
+
+    if update.updates.is_empty() && update.additions.is_empty() {
+        ui::Logger::done(&format!("{} is already up to date!", file_path));
+        return Ok(None);
+    }
+
+    Ok(Some(merge_sections(&sections, update)))
+}
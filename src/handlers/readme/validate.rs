@@ -0,0 +1,370 @@
+use crate::{
+    handlers::git::get_git_files,
+    models::{error::APIError, readme::ManifestFacts, ui},
+};
+use regex::Regex;
+use std::{collections::BTreeSet, fs, path::Path};
+
+const SHELL_LANGUAGES: [&str; 6] = ["", "bash", "sh", "shell", "console", "zsh"];
+const SOURCE_EXTENSIONS: [&str; 6] = ["rs", "py", "js", "ts", "go", "mjs"];
+
+/// A fenced code block of the README.
+struct CodeBlock<'a> {
+    language: &'a str,
+    lines: Vec<&'a str>,
+}
+
+fn code_blocks(readme: &str) -> Vec<CodeBlock<'_>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlock> = None;
+
+    for line in readme.lines() {
+        let trimmed = line.trim_start();
+        let fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+        match current.take() {
+            Some(block) if fence => blocks.push(block),
+            Some(mut block) => {
+                block.lines.push(line);
+                current = Some(block);
+            }
+            None if fence => {
+                current = Some(CodeBlock {
+                    language: trimmed[3..].trim(),
+                    lines: Vec::new(),
+                })
+            }
+            None => {}
+        }
+    }
+
+    blocks
+}
+
+/// Flags the project defines: clap `#[arg(long ...)]` fields and aliases, `.long("...")`
+/// builder calls, and `"--flag"` string literals as used by argparse, click or commander.
+fn known_flags(root: &Path, files: &[String]) -> Result<BTreeSet<String>, APIError> {
+    let pattern = |p: &str| Regex::new(p).map_err(|e| APIError::new("README check pattern", e));
+    let derive_field = pattern(
+        r#"#\[(?:arg|clap)\(((?:[^\]"]|"[^"]*")*)\)\]\s*(?:pub(?:\([^)]*\))?\s+)?(\w+)\s*:"#,
+    )?;
+    let named = pattern(r#"\b(?:long|alias|visible_alias)\s*(?:=\s*|\(\s*)"([\w-]+)""#)?;
+    let literal = pattern(r#""(--[A-Za-z][\w-]*)"#)?;
+
+    let mut flags = BTreeSet::new();
+    for file in files {
+        let is_source = Path::new(file)
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| SOURCE_EXTENSIONS.contains(&e));
+        let Some(content) = is_source
+            .then(|| fs::read_to_string(root.join(file)).ok())
+            .flatten()
+        else {
+            continue;
+        };
+
+        for caps in derive_field.captures_iter(&content) {
+            let attrs = &caps[1];
+            let has_bare_long = attrs
+                .split(',')
+                .any(|attr| attr.trim() == "long" || attr.trim() == "long()");
+            if has_bare_long {
+                flags.insert(format!("--{}", caps[2].replace('_', "-")));
+            }
+        }
+        for caps in named.captures_iter(&content) {
+            flags.insert(format!("--{}", &caps[1]));
+        }
+        for caps in literal.captures_iter(&content) {
+            flags.insert(caps[1].to_string());
+        }
+    }
+
+    Ok(flags)
+}
+
+/// Checks the flags passed to the project's own binaries in shell code blocks.
+fn check_flags(
+    blocks: &[CodeBlock],
+    binaries: &[&str],
+    flags: &BTreeSet<String>,
+    issues: &mut Vec<String>,
+) {
+    for block in blocks
+        .iter()
+        .filter(|block| SHELL_LANGUAGES.contains(&block.language))
+    {
+        for line in &block.lines {
+            let command = line.trim().trim_start_matches("$ ");
+            let command = command.split(" #").next().unwrap_or_default();
+            let mut words = command.split_whitespace().skip_while(|w| *w == "sudo");
+            let Some(program) = words.next() else {
+                continue;
+            };
+            let program = program.trim_start_matches("./");
+            if !binaries.contains(&program) {
+                continue;
+            }
+
+            for word in words.filter(|w| w.starts_with("--") && w.len() > 2) {
+                let flag = word.split('=').next().unwrap_or(word);
+                if !matches!(flag, "--help" | "--version") && !flags.contains(flag) {
+                    issues.push(format!(
+                        "`{}` uses the flag {}, which the project does not define",
+                        command.trim(),
+                        flag
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// The positional arguments of a command, skipping flags and the values of `value_flags`.
+fn positional_args<'a>(args: &[&'a str], value_flags: &[&str]) -> Vec<&'a str> {
+    let mut positional = Vec::new();
+    let mut skip_value = false;
+    for arg in args {
+        if skip_value {
+            skip_value = false;
+        } else if arg.starts_with('-') {
+            skip_value = value_flags.contains(arg);
+        } else {
+            positional.push(*arg);
+        }
+    }
+    positional
+}
+
+/// Whether an install argument is a local path, an archive or a URL rather than a package
+/// name, such as `.`, `../cli`, `/opt/pkg`, `dist/app-1.0.whl` or `git+https://...`.
+fn is_path_or_url(package: &str) -> bool {
+    matches!(package, "." | "..")
+        || ["./", "../", "/", "~/", "git+", "file:"]
+            .iter()
+            .any(|prefix| package.starts_with(prefix))
+        || package.contains("://")
+        || [".whl", ".tar.gz", ".tgz", ".zip"]
+            .iter()
+            .any(|suffix| package.ends_with(suffix))
+}
+
+/// Checks that commands installing the project itself, `cargo install <x>`,
+/// `npm i -g <x>` and `pip install <x>`, name the package the manifest defines. Commands
+/// adding dependencies or installing from paths, git or requirement files are skipped.
+fn check_install_commands(
+    blocks: &[CodeBlock],
+    manifests: &[ManifestFacts],
+    issues: &mut Vec<String>,
+) {
+    let name_of = |source: &str| {
+        manifests
+            .iter()
+            .find(|facts| facts.source == source)
+            .and_then(|facts| facts.name.as_deref())
+    };
+
+    for block in blocks
+        .iter()
+        .filter(|block| SHELL_LANGUAGES.contains(&block.language))
+    {
+        for line in &block.lines {
+            let words: Vec<&str> = line
+                .trim()
+                .trim_start_matches("$ ")
+                .split_whitespace()
+                .take_while(|w| !w.starts_with('#'))
+                .collect();
+
+            let (source, packages) = match words.as_slice() {
+                ["cargo", "install", args @ ..] => {
+                    if args.iter().any(|arg| matches!(*arg, "--path" | "--git")) {
+                        continue;
+                    }
+                    let value_flags = [
+                        "--version",
+                        "--features",
+                        "-F",
+                        "--bin",
+                        "--root",
+                        "--registry",
+                        "--index",
+                        "--target",
+                        "--profile",
+                        "-j",
+                        "--jobs",
+                    ];
+                    ("Cargo.toml", positional_args(args, &value_flags))
+                }
+                ["npm", "install" | "i", args @ ..]
+                    if args.iter().any(|arg| matches!(*arg, "-g" | "--global")) =>
+                {
+                    ("package.json", positional_args(args, &[]))
+                }
+                ["pip" | "pip3" | "pipx", "install", args @ ..] => {
+                    let value_flags = [
+                        "-r",
+                        "--requirement",
+                        "-e",
+                        "--editable",
+                        "-c",
+                        "--constraint",
+                        "-i",
+                        "--index-url",
+                        "--python",
+                    ];
+                    let packages = positional_args(args, &value_flags);
+                    if packages.len() != 1 {
+                        continue;
+                    }
+                    ("pyproject.toml", packages)
+                }
+                _ => continue,
+            };
+            let Some(expected) = name_of(source) else {
+                continue;
+            };
+
+            for package in packages {
+                // `owner/repo` is a GitHub shorthand to npm, unlike a scoped `@scope/name`.
+                let github_shorthand =
+                    source == "package.json" && !package.starts_with('@') && package.contains('/');
+                if is_path_or_url(package) || github_shorthand {
+                    continue;
+                }
+                let name = match source {
+                    "package.json" => package
+                        .rsplit_once('@')
+                        .filter(|(name, _)| !name.is_empty())
+                        .map_or(package, |(name, _)| name),
+                    _ => package
+                        .split(['=', '<', '>', '@', '[', '~'])
+                        .next()
+                        .unwrap_or(package),
+                };
+                if !name.eq_ignore_ascii_case(expected) {
+                    issues.push(format!(
+                        "`{}` installs '{}', but {} names the package '{}'",
+                        words.join(" "),
+                        name,
+                        source,
+                        expected
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Checks that relative links and images point to files next to the README in `root`.
+fn check_links(root: &Path, readme: &str, issues: &mut Vec<String>) -> Result<(), APIError> {
+    let link = Regex::new(r"\]\(([^)\s]+)[^)]*\)")
+        .map_err(|e| APIError::new("README check pattern", e))?;
+
+    let mut in_fence = false;
+    for line in readme.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        if in_fence {
+            continue;
+        }
+
+        for caps in link.captures_iter(line) {
+            let target = &caps[1];
+            if target.contains("://") || target.starts_with('#') || target.starts_with("mailto:") {
+                continue;
+            }
+            let path = target.split(['#', '?']).next().unwrap_or(target);
+            if !path.is_empty() && !root.join(path.trim_start_matches('/')).exists() {
+                issues.push(format!("The link to {} points to a missing file", path));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// What the README check found. Only `fixable` problems are sent back to the model; the
+/// install command notes are reported for the user to judge.
+#[derive(Default)]
+pub struct Findings {
+    pub fixable: Vec<String>,
+    pub notes: Vec<String>,
+}
+
+impl Findings {
+    pub fn is_empty(&self) -> bool {
+        self.fixable.is_empty() && self.notes.is_empty()
+    }
+}
+
+/// Checks the generated README of the directory `root` against the repository: linked
+/// local files, flags passed to the project's binaries and package names in install
+/// commands.
+pub fn check(root: &Path, readme: &str, manifests: &[ManifestFacts]) -> Result<Findings, APIError> {
+    let mut findings = Findings::default();
+    let blocks = code_blocks(readme);
+
+    check_links(root, readme, &mut findings.fixable)?;
+    check_install_commands(&blocks, manifests, &mut findings.notes);
+
+    let binaries: Vec<&str> = manifests
+        .iter()
+        .flat_map(|facts| facts.binaries.iter().map(String::as_str))
+        .collect();
+    if !binaries.is_empty() {
+        let flags = known_flags(root, &get_git_files(root).unwrap_or_default())?;
+        // Without any flag definitions there is nothing reliable to compare with.
+        if !flags.is_empty() {
+            check_flags(&blocks, &binaries, &flags, &mut findings.fixable);
+        }
+    }
+
+    findings.fixable.dedup();
+    findings.notes.dedup();
+    Ok(findings)
+}
+
+pub fn report(findings: &Findings) {
+    ui::Logger::warning(&format!(
+        "The README check found {} problem(s):",
+        findings.fixable.len() + findings.notes.len()
+    ));
+    for issue in findings.fixable.iter().chain(&findings.notes) {
+        ui::Logger::item(issue);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install_issues(readme: &str, source: &str, name: &str) -> Vec<String> {
+        let manifests = [ManifestFacts {
+            source: source.to_string(),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }];
+        let mut issues = Vec::new();
+        check_install_commands(&code_blocks(readme), &manifests, &mut issues);
+        issues
+    }
+
+    #[test]
+    fn checks_scoped_npm_names() {
+        let readme = "```bash\nnpm install -g @acme/cli@2\nnpm i -g @acme/other\n```\n";
+        let issues = install_issues(readme, "package.json", "@acme/cli");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("'@acme/other'"));
+    }
+
+    #[test]
+    fn skips_paths_and_urls() {
+        let readme = "```sh\npip install ./tools\npip install ../lib\npip install /opt/pkg\npip install dist/app-1.0.whl\npip install git+https://example.com/app\npip install other\n```\n";
+        let issues = install_issues(readme, "pyproject.toml", "app");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("'other'"));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    handlers::{git, readme::manifest},
+    models::{error::APIError, readme::WorkspaceMember, ui},
+};
+use serde_json::Value as JsonValue;
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+use toml::{Table, Value};
+
+/// Member directory patterns of one workspace, such as `crates/*`, and the directories it
+/// excludes.
+#[derive(Default)]
+struct Patterns {
+    manifest: &'static str,
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+/// Matches a directory against a workspace glob: `*` within one path segment and `**` for
+/// any number of segments.
+fn glob_matches(pattern: &[&str], dir: &[&str]) -> bool {
+    fn segment_matches(pattern: &str, segment: &str) -> bool {
+        match pattern.split_once('*') {
+            None => pattern == segment,
+            Some((prefix, rest)) => {
+                segment.starts_with(prefix)
+                    && (0..=segment.len() - prefix.len()).any(|skip| {
+                        segment.is_char_boundary(prefix.len() + skip)
+                            && segment_matches(rest, &segment[prefix.len() + skip..])
+                    })
+            }
+        }
+    }
+
+    match (pattern.split_first(), dir.split_first()) {
+        (None, None) => true,
+        (Some((&"**", rest)), _) => {
+            glob_matches(rest, dir) || (!dir.is_empty() && glob_matches(pattern, &dir[1..]))
+        }
+        (Some((first, rest)), Some((segment, dir_rest))) => {
+            segment_matches(first, segment) && glob_matches(rest, dir_rest)
+        }
+        _ => false,
+    }
+}
+
+fn normalize(dir: &str) -> String {
+    dir.trim()
+        .trim_start_matches("./")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(normalize)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reads `[workspace] members` and `exclude` from the root `Cargo.toml`.
+fn cargo_patterns(root: &Path) -> Option<Patterns> {
+    let manifest: Table = fs::read_to_string(root.join("Cargo.toml"))
+        .ok()?
+        .parse()
+        .ok()?;
+    let workspace = manifest.get("workspace")?.as_table()?;
+
+    Some(Patterns {
+        manifest: "Cargo.toml",
+        include: string_list(workspace.get("members")),
+        exclude: string_list(workspace.get("exclude")),
+    })
+}
+
+/// Reads `workspaces` from the root `package.json`, or `packages` from
+/// `pnpm-workspace.yaml`. Patterns starting with `!` are exclusions.
+fn npm_patterns(root: &Path) -> Option<Patterns> {
+    let from_package_json = fs::read_to_string(root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<JsonValue>(&content).ok())
+        .and_then(|manifest| {
+            let workspaces = manifest.get("workspaces")?;
+            let list = workspaces
+                .as_array()
+                .or_else(|| workspaces.get("packages")?.as_array())?;
+            Some(
+                list.iter()
+                    .filter_map(JsonValue::as_str)
+                    .map(str::to_string)
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+    let globs = from_package_json.or_else(|| {
+        let content = fs::read_to_string(root.join("pnpm-workspace.yaml")).ok()?;
+        let mut in_packages = false;
+        let mut globs = Vec::new();
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if !line.starts_with([' ', '-']) && !trimmed.is_empty() {
+                in_packages = trimmed == "packages:";
+            } else if in_packages && let Some(item) = trimmed.strip_prefix('-') {
+                globs.push(item.trim().trim_matches(['\'', '"']).to_string());
+            }
+        }
+        Some(globs)
+    })?;
+
+    let (exclude, include): (Vec<String>, Vec<String>) =
+        globs.into_iter().partition(|glob| glob.starts_with('!'));
+    Some(Patterns {
+        manifest: "package.json",
+        include: include.iter().map(|glob| normalize(glob)).collect(),
+        exclude: exclude
+            .iter()
+            .map(|glob| normalize(glob.trim_start_matches('!')))
+            .collect(),
+    })
+}
+
+/// Reads the `use` directives of `go.work`, or takes every nested `go.mod` as a module
+/// of a multi-module repository.
+fn go_patterns(root: &Path, files: &[&str]) -> Option<Patterns> {
+    let include: Vec<String> = match fs::read_to_string(root.join("go.work")) {
+        Ok(content) => {
+            let mut in_use = false;
+            let mut dirs = Vec::new();
+            for line in content
+                .lines()
+                .map(|line| line.split("//").next().unwrap_or("").trim())
+            {
+                if in_use {
+                    if line == ")" {
+                        in_use = false;
+                    } else if !line.is_empty() {
+                        dirs.push(normalize(line.trim_matches('"')));
+                    }
+                } else if line == "use (" {
+                    in_use = true;
+                } else if let Some(dir) = line.strip_prefix("use ") {
+                    dirs.push(normalize(dir.trim_matches('"')));
+                }
+            }
+            dirs
+        }
+        Err(_) => files
+            .iter()
+            .filter_map(|file| file.strip_suffix("/go.mod"))
+            .map(str::to_string)
+            .collect(),
+    };
+
+    (!include.is_empty()).then_some(Patterns {
+        manifest: "go.mod",
+        include,
+        ..Default::default()
+    })
+}
+
+/// Detects the packages of a Cargo, npm, pnpm or Go workspace rooted at `root`, among
+/// `files` relative to it.
+pub fn detect(root: &Path, files: &[&str]) -> Vec<WorkspaceMember> {
+    let workspaces = [
+        cargo_patterns(root),
+        npm_patterns(root),
+        go_patterns(root, files),
+    ];
+
+    let mut seen = BTreeSet::new();
+    let mut members = Vec::new();
+
+    for patterns in workspaces.iter().flatten() {
+        let suffix = format!("/{}", patterns.manifest);
+        let matches = |globs: &[String], dir: &str| {
+            let dir: Vec<&str> = dir.split('/').collect();
+            globs.iter().any(|glob| {
+                let glob: Vec<&str> = glob.split('/').filter(|s| !s.is_empty()).collect();
+                glob_matches(&glob, &dir)
+            })
+        };
+
+        for dir in files.iter().filter_map(|file| file.strip_suffix(&suffix)) {
+            if !matches(&patterns.include, dir)
+                || matches(&patterns.exclude, dir)
+                || !seen.insert(dir.to_string())
+            {
+                continue;
+            }
+
+            let manifest_path = format!("{}{}", dir, suffix);
+            if let Some(facts) = manifest::read_manifests_in(root, dir, files)
+                .into_iter()
+                .find(|facts| facts.source == manifest_path)
+            {
+                members.push(WorkspaceMember {
+                    path: dir.to_string(),
+                    facts,
+                });
+            }
+        }
+    }
+
+    members.sort_by(|a, b| a.path.cmp(&b.path));
+    members
+}
+
+/// Describes the workspace packages for the prompts.
+pub fn describe(members: &[WorkspaceMember]) -> String {
+    members
+        .iter()
+        .map(|member| {
+            format!(
+                "- {} (path: {}, manifest: {}, version: {}): {}",
+                member.facts.name.as_deref().unwrap_or(&member.path),
+                member.path,
+                member.facts.source,
+                member.facts.version.as_deref().unwrap_or("Unknown"),
+                member
+                    .facts
+                    .description
+                    .as_deref()
+                    .unwrap_or("No description")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Finds the directory of the workspace package named `package`, by package name or path
+/// relative to the repository root. The directory is returned relative to the current one,
+/// wherever in the repository this runs from.
+pub fn package_root(package: &str) -> Result<PathBuf, APIError> {
+    let repo_root = PathBuf::from(git::git_cmd(
+        &["rev-parse", "--show-cdup"],
+        "rev-parse --show-cdup",
+    )?);
+    let files = git::get_git_files(&repo_root)?;
+    let files: Vec<&str> = files.iter().map(AsRef::as_ref).collect();
+    let members = detect(&repo_root, &files);
+
+    let wanted = normalize(package);
+    let member = members
+        .iter()
+        .find(|member| member.facts.name.as_deref() == Some(package) || member.path == wanted)
+        .ok_or_else(|| {
+            let available = members
+                .iter()
+                .map(|member| member.facts.name.as_deref().unwrap_or(&member.path))
+                .collect::<Vec<_>>();
+            let hint = if available.is_empty() {
+                "this repository has no workspace packages".to_string()
+            } else {
+                format!("available packages: {}", available.join(", "))
+            };
+            APIError::new_msg(
+                "Workspace",
+                &format!("No package '{}' found, {}", package, hint),
+            )
+        })?;
+
+    ui::Logger::kv("Package", &format!("{} ({})", package, member.path));
+
+    Ok(repo_root.join(&member.path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, dir: &str) -> bool {
+        let pattern: Vec<&str> = pattern.split('/').collect();
+        let dir: Vec<&str> = dir.split('/').collect();
+        glob_matches(&pattern, &dir)
+    }
+
+    #[test]
+    fn matches_workspace_globs() {
+        assert!(matches("crates/*", "crates/cli"));
+        assert!(!matches("crates/*", "crates/cli/nested"));
+        assert!(!matches("crates/*", "tools/cli"));
+        assert!(matches("packages/app-*", "packages/app-web"));
+        assert!(!matches("packages/app-*", "packages/lib-web"));
+        assert!(matches("packages/**", "packages/a/b"));
+        assert!(matches("**/pkg", "pkg"));
+        assert!(matches("libs/core", "libs/core"));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::models::{error::APIError, ui};
+use std::{fs, path::Path};
+
+/// Copies an existing file to `<path>.bak` before it is overwritten.
+fn backup(path: &str) -> Result<(), APIError> {
+    if Path::new(path).exists() {
+        let backup_path = format!("{}.bak", path);
+        fs::copy(path, &backup_path).map_err(|e| APIError::new("fs::copy", e))?;
+        ui::Logger::dim(&format!("Previous version saved to {}", backup_path));
+    }
+
+    Ok(())
+}
+
+/// Writes `content` to `path`, backing up the file it replaces.
+fn write(path: &str, content: &str, action: &str) -> Result<(), APIError> {
+    backup(path)?;
+    ui::Logger::step(&format!("Writing {}...", path));
+    fs::write(path, content).map_err(|e| APIError::new("fs::write", e))?;
+
+    ui::Logger::done(&format!("{} successfully {}!", path, action));
+    ui::Logger::kv("Location", path);
+
+    Ok(())
+}
+
+/// Writes the README, or with `preview` shows the changes as a diff and lets the user
+/// write them, write them to another path or discard them. An existing file is always
+/// backed up to `<path>.bak` before it is overwritten.
+pub fn write_readme(
+    file_path: &str,
+    content: &str,
+    preview: bool,
+    action: &str,
+) -> Result<(), APIError> {
+    if !preview {
+        return write(file_path, content, action);
+    }
+
+    let current = fs::read_to_string(file_path).unwrap_or_default();
+    if current == content {
+        ui::Logger::info(&format!("{} is unchanged.", file_path));
+        return Ok(());
+    }
+
+    ui::Logger::header("PREVIEW");
+    ui::Logger::diff(file_path, &current, content);
+    eprintln!();
+
+    if ui::Input::confirm(&format!("Write these changes to {}?", file_path)) {
+        write(file_path, content, action)
+    } else if ui::Input::confirm("Write them to a different path instead?") {
+        let path = ui::Input::text_with_default("Path:", "README.generated.md");
+        write(&path, content, action)
+    } else {
+        ui::Logger::warning("Changes discarded, nothing was written.");
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        changelog::resolve_tag_range,
+        commit::{diff::get_range_diff, filter::filter_diff},
+        git::{self, git_cmd},
+        markdown,
+        utils::is_test_file,
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+use prompts::RELEASE_NOTES_PROMPT;
+use std::fs;
+
+// Longest part of a commit body included in the release context.
+const MAX_COMMIT_BODY_LENGTH: usize = 300;
+
+/// Lists changed files with their status, leaving out tests and lockfiles that never
+/// matter to users reading release notes.
+fn touched_public_files(base: &str, to: &str) -> Result<String, APIError> {
+    let output = git_cmd(
+        &["diff", "--name-status", base, to],
+        "git diff --name-status",
+    )?;
+
+    let files: Vec<String> = output
+        .lines()
+        .filter_map(|line| {
+            let (status, path) = line.split_once('\t')?;
+            let path = path.rsplit('\t').next().unwrap_or(path);
+            if is_test_file(path) || path.ends_with(".lock") || path.ends_with(".sum") {
+                return None;
+            }
+            let status = match status.chars().next() {
+                Some('A') => "added",
+                Some('D') => "deleted",
+                Some('R') => "renamed",
+                _ => "modified",
+            };
+            Some(format!("- {} ({})", path, status))
+        })
+        .collect();
+
+    Ok(files.join("\n"))
+}
+
+fn format_commits(commits: &[git::LogCommit]) -> String {
+    commits
+        .iter()
+        .map(|commit| {
+            let short_sha = &commit.sha[..7.min(commit.sha.len())];
+            let body: String = commit
+                .body
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .chars()
+                .take(MAX_COMMIT_BODY_LENGTH)
+                .collect();
+            if body.is_empty() {
+                format!("- {} ({})", commit.subject, short_sha)
+            } else {
+                format!("- {} ({})\n  {}", commit.subject, short_sha, body)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub async fn handle_release_notes(
+    target: Option<&str>,
+    output: Option<&str>,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("RELEASE NOTES");
+
+    let range = resolve_tag_range(target)?;
+    let base = range
+        .from
+        .clone()
+        .unwrap_or_else(|| git::EMPTY_TREE.to_string());
+
+    ui::Logger::step(&format!("Reading commits in {}...", range.log_range()));
+    let commits = git::log_commits(&range.log_range())?;
+    if commits.is_empty() {
+        return Err(APIError::new_msg(
+            "Release notes",
+            &format!("No commits found in {}", range.log_range()),
+        ));
+    }
+
+    let stats = git_cmd(&["diff", "--stat=120", &base, &range.to], "git diff --stat")?;
+    let files = touched_public_files(&base, &range.to)?;
+    let diff = get_range_diff(&format!("{}..{}", base, range.to))?;
+    let filtered_diff = filter_diff(&diff);
+
+    let repo_root = git_cmd(
+        &["rev-parse", "--show-toplevel"],
+        "rev-parse --show-toplevel",
+    )?;
+    let repo_name = repo_root
+        .split(std::path::MAIN_SEPARATOR)
+        .next_back()
+        .unwrap_or("Unknown");
+
+    let context_message = format!(
+        r##"# RELEASE
+Repository: {}
+Version: {}
+Previous version: {}
+
+# COMMITS ({} total, newest first)
+{}
+
+# DIFF STATISTICS
+{}
+
+# CHANGED FILES (tests and lockfiles omitted)
+{}
+
+# DIFF (filtered)
+{}"##,
+        repo_name,
+        range.label(),
+        range.from.as_deref().unwrap_or("none (first release)"),
+        commits.len(),
+        format_commits(&commits),
+        stats,
+        files,
+        filtered_diff
+    );
+
+    let mut loader = InfiniteLoader::new("Drafting release notes");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let notes = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        RELEASE_NOTES_PROMPT,
+        &[&context_message],
+        "AI provider release notes generation",
+    )
+    .await?;
+    let notes = markdown::handle_markdown_strip(&notes);
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Release notes done");
+
+    match output {
+        Some(file_path) => {
+            ui::Logger::step(&format!("Writing {}...", file_path));
+            fs::write(file_path, format!("{}\n", notes))
+                .map_err(|e| APIError::new("fs::write", e))?;
+
+            ui::Logger::done("Release notes successfully generated!");
+            ui::Logger::kv("Location", file_path);
+        }
+        None => {
+            eprintln!();
+            println!("{}", notes);
+        }
+    }
+
+    Ok(())
+}
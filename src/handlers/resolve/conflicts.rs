@@ -0,0 +1,158 @@
+/// One conflicting hunk, each side keeping its trailing newlines.
+pub struct ConflictHunk {
+    pub ours: String,
+    /// Common ancestor, present with `merge.conflictStyle = diff3` or when filled in later.
+    pub base: Option<String>,
+    pub theirs: String,
+    pub ours_label: String,
+    pub theirs_label: String,
+}
+
+pub enum Segment {
+    Text(String),
+    Conflict(ConflictHunk),
+}
+
+enum Section {
+    Ours,
+    Base,
+    Theirs,
+}
+
+fn marker_label(line: &str, marker: &str) -> Option<String> {
+    let rest = line.strip_prefix(marker)?;
+    if rest.is_empty() || rest.starts_with([' ', '\n', '\r']) {
+        Some(rest.trim().to_string())
+    } else {
+        None
+    }
+}
+
+/// Splits a file into plain text and conflict hunks delimited by `<<<<<<<`, `|||||||`,
+/// `=======` and `>>>>>>>` markers. An unterminated conflict is kept as plain text.
+pub fn parse(content: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut current: Option<(ConflictHunk, Section, String)> = None;
+
+    for line in content.split_inclusive('\n') {
+        match current.as_mut() {
+            None => {
+                if let Some(label) = marker_label(line, "<<<<<<<") {
+                    let hunk = ConflictHunk {
+                        ours: String::new(),
+                        base: None,
+                        theirs: String::new(),
+                        ours_label: label,
+                        theirs_label: String::new(),
+                    };
+                    current = Some((hunk, Section::Ours, line.to_string()));
+                } else {
+                    text.push_str(line);
+                }
+            }
+            Some((hunk, section, raw)) => {
+                raw.push_str(line);
+                if marker_label(line, "|||||||").is_some() {
+                    hunk.base = Some(String::new());
+                    *section = Section::Base;
+                } else if marker_label(line, "=======").is_some() {
+                    *section = Section::Theirs;
+                } else if let Some(label) = marker_label(line, ">>>>>>>") {
+                    hunk.theirs_label = label;
+                    if let Some((hunk, _, _)) = current.take() {
+                        if !text.is_empty() {
+                            segments.push(Segment::Text(std::mem::take(&mut text)));
+                        }
+                        segments.push(Segment::Conflict(hunk));
+                    }
+                } else {
+                    match section {
+                        Section::Ours => hunk.ours.push_str(line),
+                        Section::Base => hunk.base.get_or_insert_default().push_str(line),
+                        Section::Theirs => hunk.theirs.push_str(line),
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((_, _, raw)) = current {
+        text.push_str(&raw);
+    }
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    segments
+}
+
+pub fn conflicts(segments: &[Segment]) -> impl Iterator<Item = &ConflictHunk> {
+    segments.iter().filter_map(|segment| match segment {
+        Segment::Conflict(hunk) => Some(hunk),
+        Segment::Text(_) => None,
+    })
+}
+
+/// Returns up to `lines` lines of plain text before and after the conflict at `index`.
+pub fn surrounding_text(segments: &[Segment], index: usize, lines: usize) -> (String, String) {
+    let before = match index.checked_sub(1).map(|i| &segments[i]) {
+        Some(Segment::Text(text)) => {
+            let all: Vec<&str> = text.split_inclusive('\n').collect();
+            all[all.len().saturating_sub(lines)..].concat()
+        }
+        _ => String::new(),
+    };
+    let after = match segments.get(index + 1) {
+        Some(Segment::Text(text)) => text.split_inclusive('\n').take(lines).collect(),
+        _ => String::new(),
+    };
+
+    (before, after)
+}
+
+/// Rebuilds the file, replacing the conflicts in order with `resolutions`.
+pub fn rebuild(segments: &[Segment], resolutions: &[String]) -> String {
+    let mut resolutions = resolutions.iter();
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text.as_str(),
+            Segment::Conflict(_) => resolutions.next().map_or("", String::as_str),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_diff3_conflicts_between_text() {
+        let content =
+            "a\n<<<<<<< HEAD\nours\n||||||| base\nold\n=======\ntheirs\n>>>>>>> feature\nb\n";
+        let segments = parse(content);
+        assert_eq!(segments.len(), 3);
+
+        let hunks: Vec<&ConflictHunk> = conflicts(&segments).collect();
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].ours, "ours\n");
+        assert_eq!(hunks[0].base.as_deref(), Some("old\n"));
+        assert_eq!(hunks[0].theirs, "theirs\n");
+        assert_eq!(hunks[0].ours_label, "HEAD");
+        assert_eq!(hunks[0].theirs_label, "feature");
+
+        assert_eq!(
+            rebuild(&segments, &["merged\n".to_string()]),
+            "a\nmerged\nb\n"
+        );
+    }
+
+    #[test]
+    fn keeps_unterminated_conflicts_and_lookalikes_as_text() {
+        let content = "<<<<<<<< not a marker\n<<<<<<< HEAD\nours\n=======\n";
+        let segments = parse(content);
+        assert_eq!(conflicts(&segments).count(), 0);
+        assert_eq!(rebuild(&segments, &[]), content);
+    }
+}
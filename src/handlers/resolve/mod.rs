@@ -0,0 +1,234 @@
+mod conflicts;
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        git::{self, git_cmd},
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+use conflicts::{ConflictHunk, Segment};
+use std::{fs, io::Write, path::Path};
+
+/// Lines of unconflicted code sent around each hunk.
+const CONTEXT_LINES: usize = 15;
+
+fn write_temp(content: &str) -> Result<tempfile::NamedTempFile, APIError> {
+    let mut file = tempfile::NamedTempFile::new()
+        .map_err(|e| APIError::new_msg("Temporary file", &e.to_string()))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| APIError::new_msg("Temporary file", &e.to_string()))?;
+    Ok(file)
+}
+
+/// Fills in the common ancestor of conflicts written without a base section, by redoing
+/// the merge from the index stages with `--diff3`. Leaves the hunks untouched when the
+/// stages are missing or the result no longer lines up with the working file.
+fn fill_bases(segments: &mut [Segment], root: &str, path: &str) -> Result<(), APIError> {
+    let mut hunks: Vec<&mut ConflictHunk> = segments
+        .iter_mut()
+        .filter_map(|segment| match segment {
+            Segment::Conflict(hunk) => Some(hunk),
+            Segment::Text(_) => None,
+        })
+        .collect();
+    if hunks.iter().all(|hunk| hunk.base.is_some()) {
+        return Ok(());
+    }
+
+    let stage = |n: u8| git::read_blob(Some(root), &format!(":{}:{}", n, path));
+    let (Some(ours), Some(theirs)) = (stage(2)?, stage(3)?) else {
+        return Ok(());
+    };
+    let base = stage(1)?.unwrap_or_default();
+
+    let (ours, base, theirs) = (write_temp(&ours)?, write_temp(&base)?, write_temp(&theirs)?);
+    let merged = git::merge_file_diff3(ours.path(), base.path(), theirs.path())?;
+    let merged = conflicts::parse(&merged);
+
+    let merged_hunks: Vec<&ConflictHunk> = conflicts::conflicts(&merged).collect();
+    if merged_hunks.len() != hunks.len() {
+        return Ok(());
+    }
+    for (hunk, merged_hunk) in hunks.iter_mut().zip(merged_hunks) {
+        if hunk.base.is_none() {
+            hunk.base = merged_hunk.base.clone();
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes code fences the model may wrap around the hunk and restores the trailing
+/// newline the conflict sides end with.
+fn clean_resolution(reply: &str, hunk: &ConflictHunk) -> String {
+    let mut code = reply.trim_matches('\n');
+    if code.trim_start().starts_with("```") {
+        code = code
+            .trim_start()
+            .split_once('\n')
+            .map_or("", |(_, rest)| rest);
+        code = code.trim_end().strip_suffix("```").unwrap_or(code);
+        code = code.trim_matches('\n');
+    }
+
+    if code.trim().is_empty() {
+        return String::new();
+    }
+
+    let mut resolved = code.to_string();
+    if hunk.ours.ends_with('\n') || hunk.theirs.ends_with('\n') {
+        resolved.push('\n');
+    }
+    resolved
+}
+
+async fn resolve_hunk(
+    ai_provider: &dyn ai::AIProvider,
+    path: &str,
+    segments: &[Segment],
+    index: usize,
+    hunk: &ConflictHunk,
+) -> Result<String, APIError> {
+    let (before, after) = conflicts::surrounding_text(segments, index, CONTEXT_LINES);
+    let user_message = format!(
+        r##"# FILE
+{}
+
+# CODE BEFORE THE CONFLICT
+{}
+# OURS ({})
+{}
+# BASE
+{}
+# THEIRS ({})
+{}
+# CODE AFTER THE CONFLICT
+{}"##,
+        path,
+        before,
+        hunk.ours_label,
+        hunk.ours,
+        hunk.base.as_deref().unwrap_or("(unavailable)\n"),
+        hunk.theirs_label,
+        hunk.theirs,
+        after
+    );
+
+    let reply = ai::generate_with_retry(
+        ai_provider,
+        prompts::RESOLVE_PROMPT,
+        &[&user_message],
+        "AI provider conflict resolution",
+    )
+    .await?;
+
+    Ok(clean_resolution(&reply, hunk))
+}
+
+/// Proposes a resolution for every conflict in `path`. Returns `None` when the file has no
+/// conflict markers left.
+async fn resolve_file(
+    ai_provider: &dyn ai::AIProvider,
+    root: &str,
+    path: &str,
+    content: &str,
+) -> Result<Option<String>, APIError> {
+    let mut segments = conflicts::parse(content);
+    let count = conflicts::conflicts(&segments).count();
+    if count == 0 {
+        return Ok(None);
+    }
+
+    if let Err(e) = fill_bases(&mut segments, root, path) {
+        ui::Logger::warning(&format!("Could not recover the merge base: {}", e));
+    }
+
+    let mut loader = InfiniteLoader::new(&format!("Resolving {} conflict(s)", count));
+    loader.tick();
+
+    let mut resolutions = Vec::with_capacity(count);
+    for (index, segment) in segments.iter().enumerate() {
+        if let Segment::Conflict(hunk) = segment {
+            resolutions.push(resolve_hunk(ai_provider, path, &segments, index, hunk).await?);
+            loader.set_progress(resolutions.len() as f32 / count as f32 * 100.0);
+            loader.tick();
+        }
+    }
+
+    loader.finish(&format!("Resolved {}", path));
+
+    Ok(Some(conflicts::rebuild(&segments, &resolutions)))
+}
+
+pub async fn handle_resolve(provider: Provider, model: Option<CliModel>) -> Result<(), APIError> {
+    ui::Logger::header("RESOLVE CONFLICTS");
+
+    let root = git_cmd(
+        &["rev-parse", "--show-toplevel"],
+        "rev-parse --show-toplevel",
+    )?;
+    let files = git::git_cmd_in(
+        Some(&root),
+        &["diff", "--name-only", "--diff-filter=U"],
+        "git diff --diff-filter=U",
+    )?;
+    let files: Vec<&str> = files.lines().filter(|line| !line.is_empty()).collect();
+
+    if files.is_empty() {
+        ui::Logger::info("No conflicted files found.");
+        return Ok(());
+    }
+
+    for file in &files {
+        ui::Logger::item(file);
+    }
+
+    let ai_provider = ai::create_provider(provider, model)?;
+    let mut written = Vec::new();
+
+    for file in files {
+        ui::Logger::header(file);
+
+        let full_path = Path::new(&root).join(file);
+        let content = match fs::read_to_string(&full_path) {
+            Ok(content) => content,
+            Err(e) => {
+                ui::Logger::warning(&format!("Skipping {}: {}", file, e));
+                continue;
+            }
+        };
+
+        let Some(resolved) = resolve_file(ai_provider.as_ref(), &root, file, &content).await?
+        else {
+            ui::Logger::dim("No conflict markers left, skipping.");
+            continue;
+        };
+
+        eprintln!();
+        ui::Logger::diff(file, &content, &resolved);
+        eprintln!();
+
+        if ui::Input::confirm(&format!("Write this resolution to {}?", file)) {
+            fs::write(&full_path, resolved)
+                .map_err(|e| APIError::new_msg(&format!("Writing {}", file), &e.to_string()))?;
+            ui::Logger::success(&format!("Wrote {}", file));
+            written.push(file);
+        } else {
+            ui::Logger::warning(&format!("{} was left unchanged.", file));
+        }
+    }
+
+    if !written.is_empty() {
+        eprintln!();
+        ui::Logger::info("Review the resolutions, then stage them yourself:");
+        ui::Logger::command(&format!("git add {}", written.join(" ")));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        commit::{
+            diff::{get_git_diff, is_diff_empty},
+            filter::filter_diff,
+        },
+        json,
+    },
+    models::{
+        cli::{CliModel, CommitVariant, Provider},
+        error::APIError,
+        review::{ReviewFinding, Severity},
+        ui::{self, InfiniteLoader},
+    },
+};
+use colored::Color;
+use std::collections::BTreeMap;
+
+fn render_findings(findings: &[ReviewFinding]) {
+    let mut by_file: BTreeMap<&str, Vec<&ReviewFinding>> = BTreeMap::new();
+    for finding in findings {
+        by_file.entry(&finding.file).or_default().push(finding);
+    }
+
+    for (file, mut file_findings) in by_file {
+        file_findings.sort_by_key(|f| (f.line.unwrap_or(0), std::cmp::Reverse(f.severity)));
+
+        ui::Logger::header(file);
+        for finding in file_findings {
+            let (symbol, color) = match finding.severity {
+                Severity::High => ("✗", Color::Red),
+                Severity::Medium => ("⚠", Color::Yellow),
+                Severity::Low => ("ℹ", Color::Blue),
+            };
+            let location = finding
+                .line
+                .map_or_else(|| "-".to_string(), |line| format!("L{}", line));
+
+            ui::Logger::custom(
+                symbol,
+                &format!("[{}] {} {}", finding.severity, location, finding.message),
+                color,
+            );
+        }
+    }
+}
+
+pub async fn handle_review(
+    commit_scope: Option<CommitVariant>,
+    fail_on: Option<Severity>,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("CODE REVIEW");
+
+    // An empty or amend-only commit has nothing to review, which must not fail the hook.
+    if is_diff_empty(commit_scope.as_ref()) {
+        ui::Logger::success("No changes to review.");
+        return Ok(());
+    }
+
+    let diff = get_git_diff(commit_scope)?;
+    let filtered_contents = filter_diff(&diff);
+
+    let mut loader = InfiniteLoader::new("Reviewing the changes");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let user_message = prompts::REVIEW_USER_PROMPT.replace("{}", &filtered_contents);
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::REVIEW_PROMPT,
+        &[&user_message],
+        "AI provider code review",
+    )
+    .await?;
+
+    let json_str = json::handle_json_strip(&response);
+    let findings: Vec<ReviewFinding> =
+        serde_json::from_str(&json_str).map_err(|e| APIError::new("Invalid review JSON", e))?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Review done");
+
+    if findings.is_empty() {
+        ui::Logger::success("No issues found.");
+        return Ok(());
+    }
+
+    render_findings(&findings);
+    eprintln!();
+
+    for severity in [Severity::High, Severity::Medium, Severity::Low] {
+        let count = findings.iter().filter(|f| f.severity == severity).count();
+        if count > 0 {
+            ui::Logger::kv(&severity.to_string(), &count.to_string());
+        }
+    }
+
+    if let Some(threshold) = fail_on {
+        let failing = findings.iter().filter(|f| f.severity >= threshold).count();
+        if failing > 0 {
+            return Err(APIError::new_msg(
+                "Review",
+                &format!("{} finding(s) at or above {} severity", failing, threshold),
+            ));
+        }
+    }
+
+    Ok(())
+}
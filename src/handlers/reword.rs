@@ -0,0 +1,303 @@
+use crate::{
+    handlers::{
+        ai,
+        commit::{diff::get_commit_diff, message::generate_commit_message, prompts},
+        git::{git_cmd, git_cmd_in, git_cmd_with_env},
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui,
+    },
+};
+use std::collections::HashMap;
+
+struct RewordEntry {
+    sha: String,
+    old_message: String,
+    new_message: String,
+}
+
+fn subject(message: &str) -> &str {
+    message.lines().next().unwrap_or("").trim()
+}
+
+/// Normalises `<rev>` to `<rev>..HEAD` and checks that the range ends at `HEAD`,
+/// since only the current branch tip can be rewritten in place.
+fn resolve_range(range: &str) -> Result<String, APIError> {
+    if range.contains("...") {
+        return Err(APIError::new_msg(
+            "Reword",
+            "Symmetric ranges (a...b) are not supported, use <base>..HEAD",
+        ));
+    }
+
+    let (base, tip) = match range.split_once("..") {
+        Some((base, tip)) => (base, if tip.is_empty() { "HEAD" } else { tip }),
+        None => (range, "HEAD"),
+    };
+
+    let head = git_cmd(&["rev-parse", "HEAD"], "rev-parse HEAD")?;
+    let tip_sha = git_cmd(&["rev-parse", tip], &format!("rev-parse {}", tip))?;
+    if tip_sha != head {
+        return Err(APIError::new_msg(
+            "Reword",
+            &format!("The range must end at HEAD, but {} is not HEAD", tip),
+        ));
+    }
+
+    Ok(format!("{}..{}", base, tip))
+}
+
+/// Counts the commits of the range that are reachable from a remote-tracking branch.
+fn pushed_commits(repo: Option<&str>, range: &str) -> Result<usize, APIError> {
+    let count = |args: &[&str], context: &str| -> Result<usize, APIError> {
+        git_cmd_in(repo, args, context)?
+            .parse()
+            .map_err(|e| APIError::new(context, e))
+    };
+
+    let total = count(&["rev-list", "--count", range], "rev-list --count")?;
+    let unpushed = count(
+        &["rev-list", "--count", range, "--not", "--remotes"],
+        "rev-list --count --not --remotes",
+    )?;
+    Ok(total - unpushed)
+}
+
+/// Refuses ranges with merge commits or already pushed commits unless forced.
+fn check_rewritable(range: &str, force: bool) -> Result<(), APIError> {
+    let merges = git_cmd(&["rev-list", "--merges", range], "rev-list --merges")?;
+    if !merges.is_empty() {
+        if !force {
+            return Err(APIError::new_msg(
+                "Reword",
+                "The range contains merge commits. Use --force to rewrite it anyway.",
+            ));
+        }
+        ui::Logger::warning("The range contains merge commits, their parents will be kept.");
+    }
+
+    let pushed_count = pushed_commits(None, range)?;
+    if pushed_count > 0 {
+        if !force {
+            return Err(APIError::new_msg(
+                "Reword",
+                &format!(
+                    "{} commit(s) in the range are already on a remote. Use --force to rewrite them anyway.",
+                    pushed_count
+                ),
+            ));
+        }
+        ui::Logger::warning(&format!(
+            "{} commit(s) in the range are already on a remote.",
+            pushed_count
+        ));
+    }
+
+    Ok(())
+}
+
+/// Recreates the commits with their new messages, keeping trees, authorship and parent
+/// structure, then moves `HEAD` to the new tip. The working tree is left untouched.
+fn rewrite_commits(entries: &[RewordEntry]) -> Result<String, APIError> {
+    let mut rewritten: HashMap<String, String> = HashMap::new();
+    let mut new_head = String::new();
+
+    for entry in entries {
+        let parents: Vec<String> = git_cmd(
+            &["rev-list", "--parents", "-n", "1", &entry.sha],
+            "rev-list --parents",
+        )?
+        .split_whitespace()
+        .skip(1)
+        .map(|parent| rewritten.get(parent).cloned().unwrap_or(parent.to_string()))
+        .collect();
+
+        let tree = git_cmd(
+            &["rev-parse", &format!("{}^{{tree}}", entry.sha)],
+            "rev-parse tree",
+        )?;
+        let author = git_cmd(
+            &[
+                "log",
+                "-1",
+                "--format=%an%x00%ae%x00%ad",
+                "--date=raw",
+                &entry.sha,
+            ],
+            "log author",
+        )?;
+        let mut author_parts = author.split('\0');
+        let envs = [
+            ("GIT_AUTHOR_NAME", author_parts.next().unwrap_or_default()),
+            ("GIT_AUTHOR_EMAIL", author_parts.next().unwrap_or_default()),
+            ("GIT_AUTHOR_DATE", author_parts.next().unwrap_or_default()),
+        ];
+
+        let mut args = vec!["commit-tree", tree.as_str()];
+        for parent in &parents {
+            args.push("-p");
+            args.push(parent);
+        }
+
+        new_head = git_cmd_with_env(&args, &entry.new_message, &envs, "git commit-tree")?;
+        rewritten.insert(entry.sha.clone(), new_head.clone());
+    }
+
+    let old_head = &entries[entries.len() - 1].sha;
+    git_cmd(
+        &[
+            "update-ref",
+            "-m",
+            "gitz reword",
+            "HEAD",
+            &new_head,
+            old_head,
+        ],
+        "git update-ref",
+    )?;
+
+    Ok(new_head)
+}
+
+pub async fn handle_reword(
+    range: &str,
+    force: bool,
+    no_emoji: bool,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("REWORD COMMITS");
+
+    // Moving HEAD leaves the index alone, so staged changes would silently end up
+    // compared against the new tip instead of the commit they were staged on.
+    let staged = git_cmd(&["diff", "--cached", "--name-only"], "diff --cached")?;
+    if !staged.is_empty() {
+        return Err(APIError::new_msg(
+            "Reword",
+            "The index has staged changes. Commit or unstage them before rewording.",
+        ));
+    }
+
+    let range = resolve_range(range)?;
+    let shas: Vec<String> = git_cmd(
+        &["rev-list", "--topo-order", "--reverse", &range],
+        "rev-list",
+    )?
+    .lines()
+    .map(String::from)
+    .collect();
+
+    if shas.is_empty() {
+        return Err(APIError::new_msg(
+            "Reword",
+            &format!("No commits found in {}", range),
+        ));
+    }
+
+    check_rewritable(&range, force)?;
+
+    let ai_provider = ai::create_provider(provider, model)?;
+    let mut loader = ui::InfiniteLoader::new("Generating commit messages");
+    let mut entries = Vec::new();
+
+    for (i, sha) in shas.iter().enumerate() {
+        loader.update_message(&format!("Generating message {}/{}", i + 1, shas.len()));
+        loader.set_progress(i as f32 / shas.len() as f32 * 100.0);
+        loader.tick();
+
+        let old_message = git_cmd(&["log", "-1", "--pretty=%B", sha], "log -1 --pretty=%B")?;
+        let diff = get_commit_diff(sha)?;
+
+        // Empty commits have nothing to describe, so they keep their message.
+        let new_message = if diff.trim().is_empty() {
+            old_message.clone()
+        } else {
+            let hint = prompts::COMMIT_HINT_PROMPT.replace("{}", &old_message);
+            generate_commit_message(ai_provider.as_ref(), &diff, no_emoji, &[hint]).await?
+        };
+
+        entries.push(RewordEntry {
+            sha: sha.clone(),
+            old_message,
+            new_message,
+        });
+    }
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Commit messages done");
+    eprintln!();
+
+    let rows: Vec<Vec<String>> = entries
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.sha[..7.min(entry.sha.len())].to_string(),
+                subject(&entry.old_message).to_string(),
+                subject(&entry.new_message).to_string(),
+            ]
+        })
+        .collect();
+    ui::Logger::table(&["Commit", "Before", "After"], &rows);
+    eprintln!();
+
+    if !ui::Input::confirm(&format!(
+        "Rewrite {} commit(s) with these messages?",
+        entries.len()
+    )) {
+        ui::Logger::warning("Reword cancelled, no commits were changed.");
+        return Ok(());
+    }
+
+    let new_head = rewrite_commits(&entries)?;
+
+    ui::Logger::success("Commits reworded successfully!");
+    ui::Logger::kv("New HEAD", &new_head);
+    ui::Logger::dim("The previous history is still available through `git reflog`.");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(repo: &str, args: &[&str]) -> String {
+        git_cmd_in(Some(repo), args, "test git").unwrap()
+    }
+
+    fn commit(repo: &str, message: &str) {
+        git(
+            repo,
+            &[
+                "-c",
+                "user.name=test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--allow-empty",
+                "-m",
+                message,
+            ],
+        );
+    }
+
+    #[test]
+    fn counts_commits_reachable_from_remotes() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().to_str().unwrap();
+        git(repo, &["init", "-q"]);
+        commit(repo, "base");
+        commit(repo, "pushed");
+        let pushed = git(repo, &["rev-parse", "HEAD"]);
+        commit(repo, "local");
+
+        assert_eq!(pushed_commits(Some(repo), "HEAD~2..HEAD").unwrap(), 0);
+
+        git(repo, &["update-ref", "refs/remotes/origin/main", &pushed]);
+        assert_eq!(pushed_commits(Some(repo), "HEAD~2..HEAD").unwrap(), 1);
+        assert_eq!(pushed_commits(Some(repo), "HEAD~1..HEAD").unwrap(), 0);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    handlers::{
+        ai,
+        commit::{
+            diff::get_range_diff,
+            message::generate_commit_message,
+            prompts,
+            refs::{self, RefsConfig},
+            trailers::{self, TrailersConfig},
+        },
+        git::{self, git_cmd},
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+
+/// Options for summarising a feature branch into a single squash commit message.
+pub struct SquashOptions {
+    /// Branch or revision the feature branch will be squashed onto.
+    pub base: String,
+    pub no_emoji: bool,
+    /// Run `git merge --squash` on the base branch and commit with the message.
+    pub commit: bool,
+    pub refs: Option<RefsConfig>,
+    pub trailers: TrailersConfig,
+}
+
+fn ref_exists(reference: &str) -> bool {
+    git_cmd(
+        &["show-ref", "--verify", "--quiet", reference],
+        "git show-ref",
+    )
+    .is_ok()
+}
+
+/// Switches to `base`. A remote-tracking base such as `origin/main` is resolved to its
+/// local branch, which is created to track the remote when it does not exist yet.
+fn switch_to_base(base: &str) -> Result<(), APIError> {
+    if !ref_exists(&format!("refs/heads/{}", base))
+        && ref_exists(&format!("refs/remotes/{}", base))
+        && let Some((_, local)) = base.split_once('/')
+    {
+        if ref_exists(&format!("refs/heads/{}", local)) {
+            ui::Logger::dim(&format!(
+                "Switching to {}, the local branch of {}...",
+                local, base
+            ));
+            git_cmd(&["switch", local], &format!("git switch {}", local))?;
+        } else {
+            ui::Logger::dim(&format!("Creating {} to track {}...", local, base));
+            git_cmd(
+                &["switch", "-c", local, "--track", base],
+                &format!("git switch -c {} --track {}", local, base),
+            )?;
+        }
+        return Ok(());
+    }
+
+    ui::Logger::dim(&format!("Switching to {}...", base));
+    git_cmd(&["switch", base], &format!("git switch {}", base))?;
+    Ok(())
+}
+
+/// Switches back to the feature branch, or to its commit when it was a detached `HEAD`.
+fn switch_back(feature: &str) -> Result<String, APIError> {
+    if ref_exists(&format!("refs/heads/{}", feature)) {
+        git_cmd(&["switch", feature], &format!("git switch {}", feature))
+    } else {
+        git_cmd(
+            &["switch", "--detach", feature],
+            &format!("git switch --detach {}", feature),
+        )
+    }
+}
+
+/// Switches to `base`, squash-merges `feature` into it and commits with `message`. When
+/// the merge conflicts, the index is reset and the feature branch checked out again.
+fn squash_merge(base: &str, feature: &str, message: &str) -> Result<(), APIError> {
+    let status = git_cmd(&["status", "--porcelain", "-uno"], "status --porcelain")?;
+    if !status.is_empty() {
+        return Err(APIError::new_msg(
+            "Squash merge",
+            "The working tree has uncommitted changes. Commit or stash them first.",
+        ));
+    }
+
+    switch_to_base(base)?;
+
+    ui::Logger::dim(&format!("Squashing {} into {}...", feature, base));
+    if git_cmd(&["merge", "--squash", feature], "git merge --squash").is_err() {
+        ui::Logger::warning("The squash merge stopped with conflicts, undoing it...");
+        let recovered =
+            git_cmd(&["reset", "--merge"], "git reset --merge").and_then(|_| switch_back(feature));
+        if recovered.is_err() {
+            ui::Logger::warning("Could not undo the merge. To recover, run:");
+            ui::Logger::command("git reset --merge");
+            ui::Logger::command(&format!("git switch {}", feature));
+        } else {
+            ui::Logger::dim(&format!("Back on {}, nothing was committed.", feature));
+        }
+        return Err(APIError::new_msg(
+            "Squash merge",
+            &format!(
+                "{} does not merge cleanly into {}. Merge {} into {} and resolve the conflicts first.",
+                feature, base, base, feature
+            ),
+        ));
+    }
+
+    ui::Logger::dim("Executing git commit...");
+    git_cmd(&["commit", "-m", message], "git commit")?;
+
+    Ok(())
+}
+
+pub async fn handle_squash_message(
+    options: SquashOptions,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<String, APIError> {
+    ui::Logger::dim(&format!(
+        "Starting execution of a squash message onto {}",
+        options.base
+    ));
+
+    git_cmd(
+        &["rev-parse", "--verify", &options.base],
+        &format!("rev-parse {}", options.base),
+    )?;
+
+    let range = format!("{}..HEAD", options.base);
+    let log = git_cmd(
+        &[
+            "log",
+            "--reverse",
+            "--no-merges",
+            "--pretty=format:- %s%n%b",
+            &range,
+        ],
+        &format!("git log {}", range),
+    )?;
+    if log.trim().is_empty() {
+        return Err(APIError::new_msg(
+            "Squash message",
+            &format!("No commits found in {}", range),
+        ));
+    }
+
+    let diff = get_range_diff(&format!("{}...HEAD", options.base))?;
+
+    let mut loader = InfiniteLoader::new("Ai Agent initialization.");
+
+    loader.tick();
+    loader.tick();
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.set_progress(45.0);
+    loader.tick();
+
+    let context = [prompts::SQUASH_LOG_PROMPT.replace("{}", &log)];
+    let mut message =
+        generate_commit_message(ai_provider.as_ref(), &diff, options.no_emoji, &context).await?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+
+    loader.finish("Squash message done");
+
+    if let Some(config) = &options.refs {
+        message = refs::append_reference_footers(&message, config)?;
+    }
+    message = trailers::append_trailers(&message, &options.trailers)?;
+
+    eprintln!();
+    println!("{}", message);
+
+    if options.commit {
+        let feature = match git::current_branch()? {
+            branch if branch == "HEAD" => git_cmd(&["rev-parse", "HEAD"], "rev-parse HEAD")?,
+            branch => branch,
+        };
+
+        eprintln!();
+        if ui::Input::confirm_default_yes(&format!(
+            "Switch to {} and squash-merge {} with this message?",
+            options.base, feature
+        )) {
+            squash_merge(&options.base, &feature, &message)?;
+            ui::Logger::success("Squash commit created successfully!");
+        } else {
+            ui::Logger::warning("Squash merge cancelled, nothing was changed.");
+        }
+    }
+
+    Ok(message)
+}
@@ -0,0 +1,72 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        commit::{diff::get_git_diff, filter::filter_diff},
+        git::git_cmd,
+    },
+    models::{
+        cli::{CliModel, CommitVariant, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+
+/// Keeps the first non-empty line of the reply, without quotes or code fences.
+fn clean_stash_message(message: &str) -> String {
+    message
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("```"))
+        .unwrap_or_default()
+        .trim_matches(['"', '\'', '`'])
+        .to_string()
+}
+
+pub async fn handle_stash(provider: Provider, model: Option<CliModel>) -> Result<(), APIError> {
+    ui::Logger::header("STASH");
+
+    let diff = get_git_diff(Some(CommitVariant::Any))?;
+    let user_message = prompts::STASH_USER_PROMPT.replace("{}", &filter_diff(&diff));
+
+    let mut loader = InfiniteLoader::new("Describing the work in progress");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::STASH_PROMPT,
+        &[&user_message],
+        "AI provider stash message generation",
+    )
+    .await?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Stash message done");
+
+    let message = clean_stash_message(&response);
+    if message.is_empty() {
+        return Err(APIError::new_msg(
+            "Stash message",
+            "The AI provider returned an empty message.",
+        ));
+    }
+
+    eprintln!();
+    println!("{}", message);
+    eprintln!();
+
+    if ui::Input::confirm_default_yes("Stash the changes with this message?") {
+        ui::Logger::dim("Executing git stash push...");
+        git_cmd(&["stash", "push", "-m", &message], "git stash push")?;
+        ui::Logger::success("Changes stashed successfully!");
+    } else {
+        ui::Logger::warning("Stash cancelled, the working tree was left unchanged.");
+    }
+
+    Ok(())
+}
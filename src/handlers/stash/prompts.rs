@@ -0,0 +1,14 @@
+pub const STASH_PROMPT: &str = r#"You write Git stash messages.
+
+You receive the filtered diff of work in progress that is about to be stashed. Write ONE line that will let the developer recognise this stash later in `git stash list`.
+
+RULES:
+1. Describe what the work is about (feature, fix or experiment) and mention the main area or file touched.
+2. Keep it under 72 characters, in lowercase imperative or noun phrase style, e.g. "wip: retry logic for the upload client".
+3. Start with "wip: " unless the changes are clearly a finished fix or experiment.
+4. Output ONLY the message line, no quotes, no explanations and no code fences.
+"#;
+
+pub const STASH_USER_PROMPT: &str = r#"Write a stash message for this diff:
+
+{}"#;
@@ -0,0 +1,188 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        git::{self, git_cmd_in},
+        json,
+    },
+    models::{
+        cli::{CliModel, OutputFormat, Provider},
+        error::APIError,
+        summary::ActivitySummary,
+        ui::{self, InfiniteLoader},
+    },
+};
+use std::{fs, path::Path};
+
+// Longest part of a commit body and most touched files listed per commit.
+const MAX_BODY_HINT_LENGTH: usize = 200;
+const MAX_FILES_PER_COMMIT: usize = 8;
+
+/// Options for summarising recent activity.
+pub struct SummaryOptions {
+    /// Any date `git log --since` accepts, such as `yesterday` or `2 weeks ago`.
+    pub since: String,
+    /// Author filter; `me` stands for the `user.email` configured in each repository.
+    pub author: Option<String>,
+    /// Repositories to read, the current one when empty.
+    pub repos: Vec<String>,
+    pub format: OutputFormat,
+}
+
+/// Formats the activity of one repository for the prompt, or `None` when it has no commits.
+fn repository_activity(
+    repo: Option<&str>,
+    options: &SummaryOptions,
+) -> Result<Option<String>, APIError> {
+    let root = git_cmd_in(
+        repo,
+        &["rev-parse", "--show-toplevel"],
+        "rev-parse --show-toplevel",
+    )?;
+    let repo_name = Path::new(&root)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("Unknown")
+        .to_string();
+
+    let author = match options.author.as_deref() {
+        Some("me") => Some(git_cmd_in(
+            repo,
+            &["config", "--get", "user.email"],
+            "git config user.email",
+        )?),
+        Some(author) => Some(author.to_string()),
+        None => None,
+    };
+
+    let since = format!("--since={}", options.since);
+    let mut log_args = vec!["--all", since.as_str()];
+    let author_arg = author.map(|author| format!("--author={}", author));
+    if let Some(author_arg) = &author_arg {
+        log_args.push(author_arg);
+    }
+
+    let commits = git::query_commits(repo, &log_args)?;
+    if commits.is_empty() {
+        return Ok(None);
+    }
+    ui::Logger::dim(&format!("{}: {} commit(s)", repo_name, commits.len()));
+
+    let mut activity = format!("# REPOSITORY: {}\n", repo_name);
+    for commit in commits.iter().rev() {
+        let files = git_cmd_in(
+            repo,
+            &[
+                "diff-tree",
+                "--root",
+                "--no-commit-id",
+                "--name-only",
+                "-r",
+                &commit.sha,
+            ],
+            "git diff-tree",
+        )?;
+        let files: Vec<&str> = files.lines().take(MAX_FILES_PER_COMMIT).collect();
+        let body: String = commit
+            .body
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(MAX_BODY_HINT_LENGTH)
+            .collect();
+
+        activity.push_str(&format!("- {}\n", commit.subject));
+        if !body.is_empty() {
+            activity.push_str(&format!("  {}\n", body));
+        }
+        if !files.is_empty() {
+            activity.push_str(&format!("  files: {}\n", files.join(", ")));
+        }
+    }
+
+    Ok(Some(activity))
+}
+
+fn render_markdown(summary: &ActivitySummary) -> String {
+    let mut markdown = format!("{}\n", summary.headline);
+    for area in &summary.areas {
+        markdown.push_str(&format!("\n**{}**\n", area.area));
+        for item in &area.items {
+            markdown.push_str(&format!("- {}\n", item));
+        }
+    }
+    markdown
+}
+
+pub async fn handle_summary(
+    options: SummaryOptions,
+    output: Option<&str>,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("ACTIVITY SUMMARY");
+    ui::Logger::step(&format!("Collecting commits since {}...", options.since));
+
+    let mut activities = Vec::new();
+    if options.repos.is_empty() {
+        activities.extend(repository_activity(None, &options)?);
+    } else {
+        for repo in &options.repos {
+            activities.extend(repository_activity(Some(repo), &options)?);
+        }
+    }
+
+    if activities.is_empty() {
+        return Err(APIError::new_msg(
+            "Summary",
+            &format!("No commits found since {}", options.since),
+        ));
+    }
+
+    let context_message = activities.join("\n");
+
+    let mut loader = InfiniteLoader::new("Summarising the activity");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::SUMMARY_PROMPT,
+        &[&context_message],
+        "AI provider activity summary",
+    )
+    .await?;
+
+    let json_str = json::handle_json_strip(&response);
+    let summary: ActivitySummary =
+        serde_json::from_str(&json_str).map_err(|e| APIError::new("Invalid summary JSON", e))?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Summary done");
+
+    let rendered = match options.format {
+        OutputFormat::Markdown => render_markdown(&summary),
+        OutputFormat::Json => serde_json::to_string_pretty(&summary)
+            .map_err(|e| APIError::new("serde_json::to_string_pretty", e))?,
+    };
+
+    match output {
+        Some(file_path) => {
+            fs::write(file_path, format!("{}\n", rendered.trim_end()))
+                .map_err(|e| APIError::new("fs::write", e))?;
+            ui::Logger::done("Summary successfully generated!");
+            ui::Logger::kv("Location", file_path);
+        }
+        None => {
+            eprintln!();
+            println!("{}", rendered.trim_end());
+        }
+    }
+
+    Ok(())
+}
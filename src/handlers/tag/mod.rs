@@ -0,0 +1,119 @@
+mod prompts;
+
+use crate::{
+    handlers::{
+        ai,
+        changelog::resolve_tag_range,
+        git::{self, git_cmd},
+        markdown,
+    },
+    models::{
+        cli::{CliModel, Provider},
+        error::APIError,
+        ui::{self, InfiniteLoader},
+    },
+};
+
+pub async fn handle_tag(
+    name: &str,
+    provider: Provider,
+    model: Option<CliModel>,
+) -> Result<(), APIError> {
+    ui::Logger::header("TAG ANNOTATION");
+
+    git_cmd(
+        &["check-ref-format", &format!("refs/tags/{}", name)],
+        "git check-ref-format",
+    )
+    .map_err(|_| APIError::new_msg("Tag", &format!("'{}' is not a valid tag name", name)))?;
+    if git_cmd(
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/tags/{}", name),
+        ],
+        "rev-parse",
+    )
+    .is_ok()
+    {
+        return Err(APIError::new_msg(
+            "Tag",
+            &format!("Tag '{}' already exists", name),
+        ));
+    }
+
+    let range = resolve_tag_range(None)?;
+    ui::Logger::step(&format!("Reading commits in {}...", range.log_range()));
+
+    let commits = git::log_commits(&range.log_range())?;
+    if commits.is_empty() {
+        return Err(APIError::new_msg(
+            "Tag",
+            &format!("No commits found in {}", range.log_range()),
+        ));
+    }
+    ui::Logger::dim(&format!("Found {} commit(s).", commits.len()));
+
+    let log = commits
+        .iter()
+        .rev()
+        .map(|commit| format!("- {}\n{}", commit.subject, commit.body))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let context_message = format!(
+        r##"# NEW TAG
+{}
+
+# PREVIOUS TAG
+{}
+
+# COMMITS
+{}"##,
+        name,
+        range
+            .from
+            .as_deref()
+            .unwrap_or("None, this is the first tag"),
+        log
+    );
+
+    let mut loader = InfiniteLoader::new("Summarising the release");
+    loader.tick();
+
+    let ai_provider = ai::create_provider(provider, model)?;
+
+    loader.tick();
+    let response = ai::generate_with_retry(
+        ai_provider.as_ref(),
+        prompts::TAG_PROMPT,
+        &[&context_message],
+        "AI provider tag annotation",
+    )
+    .await?;
+
+    loader.set_progress(100.0);
+    loader.tick();
+    loader.finish("Tag annotation done");
+
+    let annotation = markdown::handle_markdown_strip(&response);
+
+    eprintln!();
+    eprintln!("{}", annotation);
+    eprintln!();
+
+    if ui::Input::confirm_default_yes(&format!("Create annotated tag {} at HEAD?", name)) {
+        ui::Logger::dim("Executing git tag -a...");
+        // The default cleanup would drop lines starting with `#` as comments.
+        git_cmd(
+            &["tag", "-a", "--cleanup=verbatim", name, "-m", &annotation],
+            &format!("git tag -a {}", name),
+        )?;
+        ui::Logger::success(&format!("Tag {} created successfully!", name));
+        ui::Logger::dim(&format!("Push it with `git push origin {}`.", name));
+    } else {
+        ui::Logger::warning("Tag cancelled, nothing was created.");
+    }
+
+    Ok(())
+}
@@ -11,3 +11,31 @@ pub fn is_config_file(file_path: &str) -> bool {
             | Some("build.gradle")
     )
 }
+
+/// Checks if a file is likely a test file based on its path and name.
+pub fn is_test_file(file_path: &str) -> bool {
+    let path = Path::new(file_path);
+
+    // Check for test-related path components (e.g., /tests/, /spec/)
+    if path.components().any(|c| {
+        let comp = c.as_os_str().to_string_lossy().to_lowercase();
+        matches!(
+            comp.as_str(),
+            "tests" | "test" | "spec" | "specs" | "__tests__"
+        )
+    }) {
+        return true;
+    }
+
+    // Check for common test file naming conventions
+    let file_name = path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    file_name.starts_with("test_")
+        || file_name.ends_with("_test.go")
+        || file_name.ends_with("_test.py")
+        || file_name.contains(".test.")
+        || file_name.contains(".spec.")
+}
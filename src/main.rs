@@ -1,31 +1,219 @@
 mod handlers;
 mod models;
 
+use crate::handlers::commit::{message::CommitOptions, refs::RefsConfig, trailers::TrailersConfig};
+use crate::handlers::explain::ExplainDepth;
+use crate::handlers::readme::{AnswerOptions, ReadmeOptions};
+use crate::handlers::squash::SquashOptions;
+use crate::handlers::summary::SummaryOptions;
 use crate::models::cli;
 use crate::models::error;
 use crate::models::ui;
 use clap::Parser;
 use dotenvy::dotenv_override;
 
+fn refs_config(cli: &cli::Cli) -> Option<RefsConfig> {
+    (!cli.no_refs).then(|| RefsConfig {
+        pattern: cli.ref_pattern.clone(),
+        ticket_footer: cli.ticket_footer.clone(),
+        issue_footer: cli.issue_footer.clone(),
+    })
+}
+
+fn trailers_config(cli: &cli::Cli) -> TrailersConfig {
+    TrailersConfig {
+        co_authors: cli.co_authors.clone(),
+        pick_co_authors: cli.pick_co_authors,
+        signoff: cli.signoff,
+        custom: cli.trailers.clone(),
+    }
+}
+
+fn answer_options(cli: &cli::Cli) -> AnswerOptions {
+    AnswerOptions {
+        answers_file: cli.answers.clone(),
+        non_interactive: cli.non_interactive,
+        save_answers: cli.save_answers.clone(),
+    }
+}
+
 async fn run(cli: cli::Cli) -> Result<(), error::APIError> {
     match cli.variant {
         cli::CliVariant::CommitMessage => {
+            let options = CommitOptions {
+                commit_scope: cli.commit_scope()?,
+                no_emoji: cli.no_emoji,
+                amend: cli.amend,
+                hint_previous: cli.hint_previous,
+                refs: refs_config(&cli),
+                trailers: trailers_config(&cli),
+            };
+
             let message = crate::handlers::commit::message::handle_commit_message(
-                cli.commit_scope,
-                cli.no_emoji,
+                options,
                 cli.provider,
                 cli.model,
             )
             .await?;
 
-            if cli.commit {
+            if cli.amend {
+                eprintln!();
+                if ui::Input::confirm_default_yes("Amend the last commit with this message?") {
+                    ui::Logger::dim("Executing git commit --amend...");
+                    handlers::git::git_cmd(
+                        &["commit", "--amend", "-m", &message],
+                        "git commit --amend",
+                    )?;
+                    ui::Logger::success("Commit amended successfully!");
+                } else {
+                    ui::Logger::warning("Amend cancelled, the last commit was left unchanged.");
+                }
+            } else if cli.commit {
                 ui::Logger::dim("Executing git commit...");
                 handlers::git::git_cmd(&["commit", "-m", &message], "git commit")?;
                 ui::Logger::success("Commit created successfully!");
             }
         }
         cli::CliVariant::Readme => {
-            crate::handlers::readme::handle_readme(cli.provider, cli.model).await?;
+            let options = ReadmeOptions {
+                update: cli.update,
+                preview: cli.dry_run,
+                diagram: cli.diagram,
+                package: cli.package.clone(),
+                answers: answer_options(&cli),
+            };
+
+            crate::handlers::readme::handle_readme(options, cli.provider, cli.model).await?;
+        }
+        cli::CliVariant::Reword => {
+            let range = cli.required_target("revision range, e.g. `gitz reword main..HEAD`")?;
+            crate::handlers::reword::handle_reword(
+                &range,
+                cli.force,
+                cli.no_emoji,
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::SquashMessage => {
+            let options = SquashOptions {
+                base: cli.required_target("base branch, e.g. `gitz squash-message main`")?,
+                no_emoji: cli.no_emoji,
+                commit: cli.commit,
+                refs: refs_config(&cli),
+                trailers: trailers_config(&cli),
+            };
+
+            crate::handlers::squash::handle_squash_message(options, cli.provider, cli.model)
+                .await?;
+        }
+        cli::CliVariant::Changelog => {
+            crate::handlers::changelog::handle_changelog(
+                cli.target.as_deref(),
+                cli.output.as_deref(),
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::ReleaseNotes => {
+            crate::handlers::release_notes::handle_release_notes(
+                cli.target.as_deref(),
+                cli.output.as_deref(),
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::PullRequest => {
+            crate::handlers::pr::handle_pr(
+                cli.target.as_deref().or(cli.pr_base.as_deref()),
+                cli.output.as_deref(),
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::Review => {
+            crate::handlers::review::handle_review(
+                cli.commit_scope()?,
+                cli.fail_on,
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::Branch => {
+            crate::handlers::branch::handle_branch(
+                cli.target.as_deref(),
+                &cli.branch_pattern,
+                &cli.ref_pattern,
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::Explain => {
+            let target = cli.required_target("revision or range, e.g. `gitz explain HEAD~2`")?;
+            let depth = if cli.brief {
+                ExplainDepth::Brief
+            } else if cli.detailed {
+                ExplainDepth::Detailed
+            } else {
+                ExplainDepth::Standard
+            };
+
+            crate::handlers::explain::handle_explain(&target, depth, cli.provider, cli.model)
+                .await?;
+        }
+        cli::CliVariant::Summary => {
+            let options = SummaryOptions {
+                since: cli.since,
+                author: cli.author,
+                repos: cli.repos,
+                format: cli.format,
+            };
+
+            crate::handlers::summary::handle_summary(
+                options,
+                cli.output.as_deref(),
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::Resolve => {
+            crate::handlers::resolve::handle_resolve(cli.provider, cli.model).await?;
+        }
+        cli::CliVariant::Stash => {
+            crate::handlers::stash::handle_stash(cli.provider, cli.model).await?;
+        }
+        cli::CliVariant::Tag => {
+            let name = cli.required_target("tag name, e.g. `gitz tag v1.4.0`")?;
+            crate::handlers::tag::handle_tag(&name, cli.provider, cli.model).await?;
+        }
+        cli::CliVariant::Docs => {
+            let kind = cli.kind.ok_or_else(|| {
+                error::APIError::new_msg(
+                    "Arguments",
+                    "Missing --kind, e.g. `gitz docs --kind contributing`",
+                )
+            })?;
+
+            crate::handlers::readme::handle_docs(
+                kind,
+                cli.output.as_deref(),
+                cli.package.as_deref(),
+                answer_options(&cli),
+                cli.provider,
+                cli.model,
+            )
+            .await?;
+        }
+        cli::CliVariant::Diagram => {
+            crate::handlers::readme::handle_diagram(cli.output.as_deref(), cli.provider, cli.model)
+                .await?;
         }
     }
     Ok(())
@@ -54,20 +242,27 @@ async fn main() {
 
     ui::Logger::header("GITZ A renovated ai commits and readmes");
 
-    let git_repo_result =
-        handlers::git::ensure_git_repo().map_err(|e| error::APIError::new("Git", e));
+    let cli = cli::Cli::parse();
+
+    // `summary --repo` reads the given repositories, so it may run from anywhere.
+    let outside_repo_allowed =
+        matches!(cli.variant, cli::CliVariant::Summary) && !cli.repos.is_empty();
+
+    if !outside_repo_allowed {
+        let git_repo_result =
+            handlers::git::ensure_git_repo().map_err(|e| error::APIError::new("Git", e));
 
-    match git_repo_result {
-        Ok(_) => ui::Logger::dim("GITZ found a git repo."),
-        Err(e) => {
-            ui::Logger::error(&e.to_string());
-            std::process::exit(1);
+        match git_repo_result {
+            Ok(_) => ui::Logger::dim("GITZ found a git repo."),
+            Err(e) => {
+                ui::Logger::error(&e.to_string());
+                std::process::exit(1);
+            }
         }
     }
-    let cli = cli::Cli::parse();
 
     if let Err(e) = run(cli).await {
-        println!();
+        eprintln!();
         ui::Logger::error(&e.to_string());
         std::process::exit(1);
     }
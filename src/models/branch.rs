@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct BranchSuggestion {
+    #[serde(rename = "type")]
+    pub branch_type: String,
+    pub slug: String,
+}
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+/// Keep a Changelog section a commit belongs to. `Ignored` covers commits that are not
+/// relevant to users, such as CI tweaks or formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ChangeCategory {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+    Security,
+    Ignored,
+}
+
+impl ChangeCategory {
+    /// Sections in the order Keep a Changelog lists them.
+    pub const SECTIONS: [ChangeCategory; 6] = [
+        ChangeCategory::Added,
+        ChangeCategory::Changed,
+        ChangeCategory::Deprecated,
+        ChangeCategory::Removed,
+        ChangeCategory::Fixed,
+        ChangeCategory::Security,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ChangeCategory::Added => "Added",
+            ChangeCategory::Changed => "Changed",
+            ChangeCategory::Deprecated => "Deprecated",
+            ChangeCategory::Removed => "Removed",
+            ChangeCategory::Fixed => "Fixed",
+            ChangeCategory::Security => "Security",
+            ChangeCategory::Ignored => "Ignored",
+        }
+    }
+}
+
+/// A commit placed into a changelog section.
+#[derive(Debug)]
+pub struct ChangelogEntry {
+    pub category: ChangeCategory,
+    pub description: String,
+    pub sha: String,
+}
+
+/// The model's classification of a non-conventional commit.
+#[derive(Debug, Deserialize)]
+pub struct CommitClassification {
+    pub index: usize,
+    pub category: ChangeCategory,
+    pub summary: Option<String>,
+}
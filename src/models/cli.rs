@@ -2,12 +2,42 @@ use std::fmt;
 
 use clap::{Parser, ValueEnum};
 
+use crate::models::{error::APIError, readme::DocKind, review::Severity};
+
 #[derive(Clone, Debug, ValueEnum)]
 pub enum CliVariant {
     #[value(name = "commit")]
     CommitMessage,
     #[value(name = "readme")]
     Readme,
+    #[value(name = "reword")]
+    Reword,
+    #[value(name = "squash-message")]
+    SquashMessage,
+    #[value(name = "changelog")]
+    Changelog,
+    #[value(name = "release-notes")]
+    ReleaseNotes,
+    #[value(name = "pr")]
+    PullRequest,
+    #[value(name = "review")]
+    Review,
+    #[value(name = "branch")]
+    Branch,
+    #[value(name = "explain")]
+    Explain,
+    #[value(name = "summary")]
+    Summary,
+    #[value(name = "resolve")]
+    Resolve,
+    #[value(name = "stash")]
+    Stash,
+    #[value(name = "tag")]
+    Tag,
+    #[value(name = "docs")]
+    Docs,
+    #[value(name = "diagram")]
+    Diagram,
 }
 
 #[derive(Clone, Debug, ValueEnum)]
@@ -33,6 +63,15 @@ pub enum CommitVariant {
     Any,
 }
 
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum OutputFormat {
+    #[default]
+    #[value(name = "markdown")]
+    Markdown,
+    #[value(name = "json")]
+    Json,
+}
+
 #[derive(Clone, Debug, ValueEnum, Default)]
 pub enum Provider {
     #[default]
@@ -58,13 +97,24 @@ pub struct Cli {
     #[arg(value_enum)]
     pub variant: CliVariant,
 
-    #[arg(value_enum)]
-    pub commit_scope: Option<CommitVariant>,
+    /// Depends on the variant: the diff scope (stage or any) for `commit` and `review`,
+    /// a revision or range for `reword`, `explain`, `changelog` and `release-notes`,
+    /// a base branch for `squash-message` and `pr`, a work description for `branch`,
+    /// or the new tag name for `tag`
+    pub target: Option<String>,
 
     /// Automatically commit with the generated message
     #[arg(long)]
     pub commit: bool,
 
+    /// Rewrite the last commit's message from `HEAD~1..HEAD` plus staged changes
+    #[arg(long)]
+    pub amend: bool,
+
+    /// Use the existing message of the last commit as a hint when amending
+    #[arg(long, requires = "amend")]
+    pub hint_previous: bool,
+
     /// Generate commit message without emojis (follows conventional git commit format)
     #[arg(long)]
     pub no_emoji: bool,
@@ -76,4 +126,140 @@ pub struct Cli {
     /// AI model to use
     #[arg(long)]
     pub model: Option<CliModel>,
+
+    /// Branch `pr` compares against when no target is given
+    #[arg(long, env = "GITZ_PR_BASE")]
+    pub pr_base: Option<String>,
+
+    /// Pattern for `branch` names, using `{type}`, `{slug}` and `{ticket}` placeholders
+    #[arg(long, env = "GITZ_BRANCH_PATTERN", default_value = "{type}/{slug}")]
+    pub branch_pattern: String,
+
+    /// Give a short `explain` answer of a few sentences
+    #[arg(long, conflicts_with = "detailed")]
+    pub brief: bool,
+
+    /// Give a detailed `explain` answer with a walk-through of every notable file
+    #[arg(long)]
+    pub detailed: bool,
+
+    /// Only summarise commits newer than this date (any `git log --since` value)
+    #[arg(long, default_value = "yesterday")]
+    pub since: String,
+
+    /// Only summarise commits by this author (`me` for your git user)
+    #[arg(long)]
+    pub author: Option<String>,
+
+    /// Repository to include in `summary` (repeatable, defaults to the current one)
+    #[arg(long = "repo")]
+    pub repos: Vec<String>,
+
+    /// Output format for `summary`
+    #[arg(long, value_enum, default_value = "markdown")]
+    pub format: OutputFormat,
+
+    /// Update the existing README section by section instead of replacing it
+    #[arg(long)]
+    pub update: bool,
+
+    /// Show the README changes as a diff and ask before writing them
+    #[arg(long, visible_alias = "preview")]
+    pub dry_run: bool,
+
+    /// TOML file answering the `readme` and `docs` questions, keyed by question text or number
+    #[arg(long, value_name = "FILE")]
+    pub answers: Option<String>,
+
+    /// Never prompt for `readme` and `docs` questions, taking the first option when unanswered
+    #[arg(long)]
+    pub non_interactive: bool,
+
+    /// Save the `readme` and `docs` answers to this TOML file for later runs
+    #[arg(long, value_name = "FILE")]
+    pub save_answers: Option<String>,
+
+    /// Add an architecture section with a Mermaid diagram of the module graph to the `readme`
+    #[arg(long)]
+    pub diagram: bool,
+
+    /// Workspace package to write the `readme` or `docs` for, by package name or directory
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
+
+    /// Document to generate with `docs`
+    #[arg(long, value_enum)]
+    pub kind: Option<DocKind>,
+
+    /// Fail `review` when a finding has at least this severity
+    #[arg(long, value_enum)]
+    pub fail_on: Option<Severity>,
+
+    /// Write the result to this file instead of the default location
+    #[arg(long, short)]
+    pub output: Option<String>,
+
+    /// Rewrite commits even if they were already pushed or include merges
+    #[arg(long)]
+    pub force: bool,
+
+    /// Do not add issue or ticket footers parsed from the branch name
+    #[arg(long)]
+    pub no_refs: bool,
+
+    /// Regex used to find ticket keys (`ticket` group) and issue ids (`issue` group) in the branch name
+    #[arg(
+        long,
+        env = "GITZ_REF_PATTERN",
+        default_value = r"(?P<ticket>[A-Z][A-Z0-9]+-\d+)|(?:^|[\s/_-])(?:#|(?i:issue|gh)-)(?P<issue>\d+)\b"
+    )]
+    pub ref_pattern: String,
+
+    /// Footer format for ticket keys, `{}` is replaced by the key
+    #[arg(long, env = "GITZ_TICKET_FOOTER", default_value = "Refs: {}")]
+    pub ticket_footer: String,
+
+    /// Footer format for issue ids, `{}` is replaced by the id
+    #[arg(long, env = "GITZ_ISSUE_FOOTER", default_value = "Closes: #{}")]
+    pub issue_footer: String,
+
+    /// Add a `Co-authored-by:` trailer, written as `Name <email>` (repeatable)
+    #[arg(long = "co-author", env = "GITZ_CO_AUTHORS", value_delimiter = ';')]
+    pub co_authors: Vec<String>,
+
+    /// Pick co-authors from the repository history (`git shortlog -sne`)
+    #[arg(long)]
+    pub pick_co_authors: bool,
+
+    /// Add a `Signed-off-by:` trailer for the configured git user
+    #[arg(long, env = "GITZ_SIGNOFF")]
+    pub signoff: bool,
+
+    /// Add a custom trailer, written as `Key: value` (repeatable)
+    #[arg(long = "trailer", env = "GITZ_TRAILERS", value_delimiter = ';')]
+    pub trailers: Vec<String>,
+}
+
+impl Cli {
+    /// Parses the positional target as a commit scope (`stage` or `any`).
+    pub fn commit_scope(&self) -> Result<Option<CommitVariant>, APIError> {
+        self.target
+            .as_deref()
+            .map(|target| {
+                CommitVariant::from_str(target, true).map_err(|_| {
+                    APIError::new_msg(
+                        "Commit scope",
+                        &format!("Unknown scope '{}', expected 'stage' or 'any'", target),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns the positional target, failing with a message naming what it should be.
+    pub fn required_target(&self, what: &str) -> Result<String, APIError> {
+        self.target
+            .clone()
+            .ok_or_else(|| APIError::new_msg("Arguments", &format!("Missing {}", what)))
+    }
 }
@@ -1,4 +1,8 @@
+pub mod branch;
+pub mod changelog;
 pub mod cli;
 pub mod error;
 pub mod readme;
+pub mod review;
+pub mod summary;
 pub mod ui;
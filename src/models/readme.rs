@@ -1,9 +1,42 @@
+use clap::ValueEnum;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Deserialize)]
 pub struct ReadmeAnalysis {
     pub questions: Vec<Question>,
     pub extracted: ExtractedData,
+    /// Facts read from the project manifests, which take precedence over `extracted`.
+    #[serde(skip)]
+    pub manifests: Vec<ManifestFacts>,
+    /// Packages of the workspace, when the repository is a monorepo.
+    #[serde(skip)]
+    pub members: Vec<WorkspaceMember>,
+}
+
+/// Facts read exactly from a manifest such as `Cargo.toml` or `package.json`.
+#[derive(Debug, Default)]
+pub struct ManifestFacts {
+    /// Path of the manifest in the repository.
+    pub source: String,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub license: Option<String>,
+    pub description: Option<String>,
+    pub dependencies: Vec<String>,
+    pub binaries: Vec<String>,
+    /// Published to its registry, as far as the manifest tells: not opted out and naming
+    /// where the project lives (`repository`, `[project.urls]`), or a Go module path
+    /// under a host.
+    pub published: bool,
+}
+
+/// A package of a Cargo, npm, pnpm or Go workspace.
+#[derive(Debug)]
+pub struct WorkspaceMember {
+    /// Directory of the package, relative to the repository root.
+    pub path: String,
+    pub facts: ManifestFacts,
 }
 
 #[derive(Debug, Deserialize)]
@@ -19,4 +52,79 @@ pub struct ExtractedData {
     pub tech_stack: Vec<String>,
     pub main_functionality: Vec<String>,
     pub inferred_features: Option<Vec<String>>,
+    pub dependencies: Option<Vec<String>>,
+    pub cli_commands: Option<Vec<String>>,
+    pub api_endpoints: Option<Vec<String>>,
+    pub public_api: Option<Vec<String>>,
+    pub security_analysis: Option<Vec<String>>,
+    pub authentication_methods: Option<Vec<String>>,
+    pub license: Option<String>,
+    pub version: Option<String>,
+}
+
+/// Repository documents other than the README that `docs` can generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DocKind {
+    #[value(name = "contributing")]
+    Contributing,
+    #[value(name = "security")]
+    Security,
+    #[value(name = "code-of-conduct")]
+    CodeOfConduct,
+    #[value(name = "issue-templates")]
+    IssueTemplates,
+    #[value(name = "usage")]
+    Usage,
+}
+
+impl DocKind {
+    /// Default location of the document, a directory for issue templates.
+    pub fn default_path(&self) -> &'static str {
+        match self {
+            DocKind::Contributing => "CONTRIBUTING.md",
+            DocKind::Security => "SECURITY.md",
+            DocKind::CodeOfConduct => "CODE_OF_CONDUCT.md",
+            DocKind::IssueTemplates => ".github/ISSUE_TEMPLATE",
+            DocKind::Usage => "USAGE.md",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            DocKind::Contributing => "contributing guide",
+            DocKind::Security => "security policy",
+            DocKind::CodeOfConduct => "code of conduct",
+            DocKind::IssueTemplates => "issue templates",
+            DocKind::Usage => "usage guide",
+        }
+    }
+}
+
+/// The model's changes to an existing README, by section index.
+#[derive(Debug, Deserialize)]
+pub struct ReadmeUpdate {
+    #[serde(default)]
+    pub updates: Vec<SectionUpdate>,
+    #[serde(default)]
+    pub additions: Vec<SectionAddition>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SectionUpdate {
+    pub index: usize,
+    pub content: String,
+}
+
+/// A section missing from the README, inserted after the section at `after`.
+#[derive(Debug, Deserialize)]
+pub struct SectionAddition {
+    pub after: usize,
+    pub content: String,
+}
+
+/// Names the model gives to the components of the architecture diagram, by directory.
+#[derive(Debug, Deserialize)]
+pub struct DiagramLabels {
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
 }
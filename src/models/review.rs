@@ -0,0 +1,71 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer, de};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Severity {
+    #[value(name = "low")]
+    Low,
+    #[value(name = "medium")]
+    Medium,
+    #[value(name = "high")]
+    High,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    /// Parses a severity in any case, also accepting the common synonyms models use.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "low" | "minor" | "info" => Ok(Severity::Low),
+            "medium" | "moderate" | "warning" => Ok(Severity::Medium),
+            "high" | "major" | "critical" | "blocker" => Ok(Severity::High),
+            other => Err(format!("unknown severity `{}`", other)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewFinding {
+    pub file: String,
+    pub line: Option<u32>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_parses_in_any_case() {
+        let finding: ReviewFinding = serde_json::from_str(
+            r#"{"file": "a.rs", "line": 1, "severity": "High", "message": "m"}"#,
+        )
+        .unwrap();
+        assert_eq!(finding.severity, Severity::High);
+        assert_eq!("CRITICAL".parse::<Severity>(), Ok(Severity::High));
+        assert_eq!(" Medium ".parse::<Severity>(), Ok(Severity::Medium));
+        assert!("urgent".parse::<Severity>().is_err());
+    }
+}
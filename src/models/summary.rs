@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActivitySummary {
+    pub headline: String,
+    pub areas: Vec<AreaSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AreaSummary {
+    pub area: String,
+    pub items: Vec<String>,
+}
@@ -8,8 +8,8 @@ pub struct Input;
 impl Input {
     // Simple text input with prompt
     pub fn text(prompt: &str) -> String {
-        print!("{} {} ", "?".cyan().bold(), prompt.bright_white());
-        io::stdout().flush().unwrap();
+        eprint!("{} {} ", "?".cyan().bold(), prompt.bright_white());
+        io::stderr().flush().unwrap();
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
@@ -18,13 +18,13 @@ impl Input {
 
     // Text input with default value shown
     pub fn text_with_default(prompt: &str, default: &str) -> String {
-        print!(
+        eprint!(
             "{} {} {}: ",
             "?".cyan().bold(),
             prompt.bright_white(),
             format!("(default: {})", default).dimmed()
         );
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
@@ -38,9 +38,10 @@ impl Input {
     }
 
     // Password/secret input (hidden)
+    #[allow(dead_code)]
     pub fn password(prompt: &str) -> String {
-        print!("{} {} ", "🔒".yellow().bold(), prompt.bright_white());
-        io::stdout().flush().unwrap();
+        eprint!("{} {} ", "🔒".yellow().bold(), prompt.bright_white());
+        io::stderr().flush().unwrap();
 
         // Use rpassword crate for secure password input
         // For basic version without external crate:
@@ -51,13 +52,13 @@ impl Input {
 
     // Yes/No confirmation
     pub fn confirm(prompt: &str) -> bool {
-        print!(
+        eprint!(
             "{} {} {}: ",
             "?".cyan().bold(),
             prompt.bright_white(),
             "[y/N]".dimmed()
         );
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
@@ -67,13 +68,13 @@ impl Input {
 
     // Yes/No with default true
     pub fn confirm_default_yes(prompt: &str) -> bool {
-        print!(
+        eprint!(
             "{} {} {}: ",
             "?".cyan().bold(),
             prompt.bright_white(),
             "[Y/n]".dimmed()
         );
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
@@ -84,10 +85,10 @@ impl Input {
 
     // Multiple choice selection
     pub fn select(prompt: &str, options: &[&str]) -> usize {
-        println!("{} {}", "?".cyan().bold(), prompt.bright_white());
+        eprintln!("{} {}", "?".cyan().bold(), prompt.bright_white());
 
         for (i, option) in options.iter().enumerate() {
-            println!(
+            eprintln!(
                 "  {} {}",
                 format!("{})", i + 1).bright_cyan(),
                 option.white()
@@ -95,57 +96,105 @@ impl Input {
         }
 
         loop {
-            print!(
+            eprint!(
                 "{} {}{}{}: ",
                 "→".cyan().bold(),
                 "Select (1-".bright_white(),
                 options.len().to_string().bright_white(),
                 ")".bright_white()
             );
-            io::stdout().flush().unwrap();
+            io::stderr().flush().unwrap();
 
             let mut input = String::new();
             io::stdin().read_line(&mut input).unwrap();
 
-            if let Ok(choice) = input.trim().parse::<usize>() {
-                if choice > 0 && choice <= options.len() {
-                    return choice - 1;
-                }
+            if let Ok(choice) = input.trim().parse::<usize>()
+                && choice > 0
+                && choice <= options.len()
+            {
+                return choice - 1;
             }
 
             Logger::error("Invalid selection. Please try again.");
         }
     }
+    // Multiple choice selection allowing several picks (empty input selects nothing)
+    pub fn multi_select(prompt: &str, options: &[&str]) -> Vec<usize> {
+        eprintln!("{} {}", "?".cyan().bold(), prompt.bright_white());
+
+        for (i, option) in options.iter().enumerate() {
+            eprintln!(
+                "  {} {}",
+                format!("{})", i + 1).bright_cyan(),
+                option.white()
+            );
+        }
+
+        loop {
+            eprint!(
+                "{} {}{}{}: ",
+                "→".cyan().bold(),
+                "Select (comma-separated 1-".bright_white(),
+                options.len().to_string().bright_white(),
+                ")".bright_white()
+            );
+            io::stderr().flush().unwrap();
+
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).unwrap();
+
+            let choices: Result<Vec<usize>, _> = input
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse::<usize>())
+                .collect();
+
+            if let Ok(choices) = choices
+                && choices.iter().all(|&c| c > 0 && c <= options.len())
+            {
+                let mut picked: Vec<usize> = choices.into_iter().map(|c| c - 1).collect();
+                picked.sort();
+                picked.dedup();
+                return picked;
+            }
+
+            Logger::error("Invalid selection. Please try again.");
+        }
+    }
+
     // Multiple choice with descriptions
+    #[allow(dead_code)]
     pub fn select_with_desc(prompt: &str, options: &[(&str, &str)]) -> usize {
-        println!("{} {}", "?".cyan().bold(), prompt.bright_white());
+        eprintln!("{} {}", "?".cyan().bold(), prompt.bright_white());
 
         for (i, (option, desc)) in options.iter().enumerate() {
-            println!(
+            eprintln!(
                 "  {} {}",
                 format!("{})", i + 1).bright_cyan(),
                 option.white().bold()
             );
-            println!("     {}", desc.dimmed());
+            eprintln!("     {}", desc.dimmed());
         }
 
         loop {
-            print!(
+            eprint!(
                 "{} {}{}{}: ",
                 "→".cyan().bold(),
                 "Select (1-".bright_white(),
                 options.len().to_string().bright_white(),
                 ")".bright_white()
             );
-            io::stdout().flush().unwrap();
+            io::stderr().flush().unwrap();
 
             let mut input = String::new();
             io::stdin().read_line(&mut input).unwrap();
 
-            if let Ok(choice) = input.trim().parse::<usize>() {
-                if choice > 0 && choice <= options.len() {
-                    return choice - 1;
-                }
+            if let Ok(choice) = input.trim().parse::<usize>()
+                && choice > 0
+                && choice <= options.len()
+            {
+                return choice - 1;
             }
 
             Logger::error("Invalid selection. Please try again.");
@@ -153,13 +202,14 @@ impl Input {
     }
 
     // Number input with validation
+    #[allow(dead_code)]
     pub fn number<T: std::str::FromStr>(prompt: &str) -> T
     where
         T::Err: std::fmt::Display,
     {
         loop {
-            print!("{} {} ", "?".cyan().bold(), prompt.bright_white());
-            io::stdout().flush().unwrap();
+            eprint!("{} {} ", "?".cyan().bold(), prompt.bright_white());
+            io::stderr().flush().unwrap();
 
             let mut input = String::new();
             io::stdin().read_line(&mut input).unwrap();
@@ -172,19 +222,20 @@ impl Input {
     }
 
     // Number input with range validation
+    #[allow(dead_code)]
     pub fn number_in_range<T>(prompt: &str, min: T, max: T) -> T
     where
         T: std::str::FromStr + PartialOrd + std::fmt::Display + Copy,
         T::Err: std::fmt::Display,
     {
         loop {
-            print!(
+            eprint!(
                 "{} {} {}: ",
                 "?".cyan().bold(),
                 prompt.bright_white(),
                 format!("[{}-{}]", min, max).dimmed()
             );
-            io::stdout().flush().unwrap();
+            io::stderr().flush().unwrap();
 
             let mut input = String::new();
             io::stdin().read_line(&mut input).unwrap();
@@ -198,9 +249,10 @@ impl Input {
     }
 
     // Multi-line text input (ends with empty line)
+    #[allow(dead_code)]
     pub fn multiline(prompt: &str) -> String {
-        println!("{} {}", "?".cyan().bold(), prompt.bright_white());
-        println!(
+        eprintln!("{} {}", "?".cyan().bold(), prompt.bright_white());
+        eprintln!(
             "  {} {}",
             "ℹ".blue(),
             "Press Enter twice to finish".dimmed()
@@ -222,14 +274,15 @@ impl Input {
     }
 
     // List input (comma-separated)
+    #[allow(dead_code)]
     pub fn list(prompt: &str) -> Vec<String> {
-        print!(
+        eprint!(
             "{} {} {}: ",
             "?".cyan().bold(),
             prompt.bright_white(),
             "(comma-separated)".dimmed()
         );
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
@@ -242,13 +295,14 @@ impl Input {
     }
 
     // Custom validation
+    #[allow(dead_code)]
     pub fn validated<F>(prompt: &str, validator: F) -> String
     where
         F: Fn(&str) -> Result<(), String>,
     {
         loop {
-            print!("{} {} ", "?".cyan().bold(), prompt.bright_white());
-            io::stdout().flush().unwrap();
+            eprint!("{} {} ", "?".cyan().bold(), prompt.bright_white());
+            io::stderr().flush().unwrap();
 
             let mut input = String::new();
             io::stdin().read_line(&mut input).unwrap();
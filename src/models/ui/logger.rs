@@ -4,40 +4,43 @@ pub struct Logger;
 
 impl Logger {
     // Clear the entire screen
+    #[allow(dead_code)]
     pub fn clear_screen() {
-        print!("\x1B[2J\x1B[1;1H");
+        eprint!("\x1B[2J\x1B[1;1H");
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     // Clear current line
     pub fn clear_line() {
-        print!("\r\x1B[K");
+        eprint!("\r\x1B[K");
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     // Clear last N lines
+    #[allow(dead_code)]
     pub fn clear_last_lines(n: usize) {
         for _ in 0..n {
-            print!("\x1B[1A"); // Move up one line
-            print!("\x1B[2K"); // Clear line
+            eprint!("\x1B[1A"); // Move up one line
+            eprint!("\x1B[2K"); // Clear line
         }
-        print!("\r");
+        eprint!("\r");
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     // Move cursor up N lines
+    #[allow(dead_code)]
     pub fn move_up(n: usize) {
-        print!("\x1B[{}A", n);
+        eprint!("\x1B[{}A", n);
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     // Success messages - green with checkmark
     pub fn success(msg: &str) {
-        println!("{} {}", "✓".green().bold(), msg.bright_green());
+        eprintln!("{} {}", "✓".green().bold(), msg.bright_green());
     }
 
     // Error messages - red with cross
@@ -58,49 +61,51 @@ impl Logger {
 
     // Warning messages - yellow with warning symbol
     pub fn warning(msg: &str) {
-        println!("{} {}", "⚠".yellow().bold(), msg.bright_yellow());
+        eprintln!("{} {}", "⚠".yellow().bold(), msg.bright_yellow());
     }
 
     // Info messages - blue with info symbol
     pub fn info(msg: &str) {
-        println!("{} {}", "ℹ".blue().bold(), msg.bright_blue());
+        eprintln!("{} {}", "ℹ".blue().bold(), msg.bright_blue());
     }
 
     // Step/progress messages - cyan with arrow
     pub fn step(msg: &str) {
-        println!("{} {}", "→".cyan().bold(), msg.cyan());
+        eprintln!("{} {}", "→".cyan().bold(), msg.cyan());
     }
 
     // Highlight/emphasis - magenta
+    #[allow(dead_code)]
     pub fn highlight(msg: &str) {
-        println!("{} {}", "★".magenta().bold(), msg.bright_magenta());
+        eprintln!("{} {}", "★".magenta().bold(), msg.bright_magenta());
     }
 
     // Subtle/dimmed messages
     pub fn dim(msg: &str) {
-        println!("  {}", msg.dimmed());
+        eprintln!("  {}", msg.dimmed());
     }
 
     // Header/section separator
     pub fn header(msg: &str) {
-        println!("\n{}", msg.bright_white().bold().underline());
+        eprintln!("\n{}", msg.bright_white().bold().underline());
     }
 
     // Loading/processing animation frame
+    #[allow(dead_code)]
     pub fn processing(msg: &str) {
-        print!("\r{} {}", "◐".cyan().bold(), msg.cyan());
+        eprint!("\r{} {}", "◐".cyan().bold(), msg.cyan());
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     // Command/code display
     pub fn command(cmd: &str) {
-        println!("  {} {}", "$".bright_black(), cmd.white().on_black());
+        eprintln!("  {} {}", "$".bright_black(), cmd.white().on_black());
     }
 
     // Key-value pair display
     pub fn kv(key: &str, value: &str) {
-        println!(
+        eprintln!(
             "  {} {}",
             format!("{}:", key).bright_white().bold(),
             value.bright_cyan()
@@ -109,16 +114,85 @@ impl Logger {
 
     // List item
     pub fn item(msg: &str) {
-        println!("  {} {}", "•".bright_white(), msg.white());
+        eprintln!("  {} {}", "•".bright_white(), msg.white());
+    }
+
+    // Simple column table, cells longer than the column limit are shortened
+    pub fn table(headers: &[&str], rows: &[Vec<String>]) {
+        const MAX_CELL_WIDTH: usize = 60;
+
+        let shorten = |cell: &str| -> String {
+            if cell.chars().count() > MAX_CELL_WIDTH {
+                let cut: String = cell.chars().take(MAX_CELL_WIDTH - 1).collect();
+                format!("{}…", cut)
+            } else {
+                cell.to_string()
+            }
+        };
+
+        let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+        for row in rows {
+            for (i, cell) in row.iter().enumerate().take(widths.len()) {
+                widths[i] = widths[i].max(shorten(cell).chars().count());
+            }
+        }
+
+        let pad = |cell: &str, width: usize| -> String {
+            format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+        };
+
+        let header_line: Vec<String> = headers
+            .iter()
+            .enumerate()
+            .map(|(i, h)| pad(h, widths[i]))
+            .collect();
+        eprintln!("  {}", header_line.join("  ").bright_white().bold());
+
+        let separator: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+        eprintln!("  {}", separator.join("  ").dimmed());
+
+        for row in rows {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .take(widths.len())
+                .map(|(i, cell)| pad(&shorten(cell), widths[i]))
+                .collect();
+            eprintln!("  {}", cells.join("  ").white());
+        }
+    }
+
+    // Colourised unified diff between two versions of a file
+    pub fn diff(path: &str, old: &str, new: &str) {
+        let diff = similar::TextDiff::from_lines(old, new);
+        let unified = diff
+            .unified_diff()
+            .context_radius(3)
+            .header(&format!("a/{}", path), &format!("b/{}", path))
+            .to_string();
+
+        for line in unified.lines() {
+            if line.starts_with("+++") || line.starts_with("---") {
+                eprintln!("  {}", line.bright_white().bold());
+            } else if line.starts_with("@@") {
+                eprintln!("  {}", line.cyan());
+            } else if line.starts_with('+') {
+                eprintln!("  {}", line.green());
+            } else if line.starts_with('-') {
+                eprintln!("  {}", line.red());
+            } else {
+                eprintln!("  {}", line.dimmed());
+            }
+        }
     }
 
     // Completion message with emoji
     pub fn done(msg: &str) {
-        println!("\n{} {}\n", "🎉".bold(), msg.bright_green().bold());
+        eprintln!("\n{} {}\n", "🎉".bold(), msg.bright_green().bold());
     }
 
     // Custom colored message
     pub fn custom(symbol: &str, msg: &str, color: Color) {
-        println!("{} {}", symbol.color(color).bold(), msg.color(color));
+        eprintln!("{} {}", symbol.color(color).bold(), msg.color(color));
     }
 }
@@ -4,4 +4,6 @@ pub mod progress;
 
 pub use input::Input;
 pub use logger::Logger;
-pub use progress::{InfiniteLoader, ProgressBar};
+pub use progress::InfiniteLoader;
+#[allow(unused_imports)]
+pub use progress::ProgressBar;
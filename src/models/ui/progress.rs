@@ -1,12 +1,14 @@
 use colored::*;
 
 // Progress bar helper
+#[allow(dead_code)]
 pub struct ProgressBar {
     total: usize,
     current: usize,
     label: String,
 }
 
+#[allow(dead_code)]
 impl ProgressBar {
     pub fn new(total: usize, label: &str) -> Self {
         Self {
@@ -28,7 +30,7 @@ impl ProgressBar {
             "░".repeat(empty).dimmed()
         );
 
-        print!(
+        eprint!(
             "\r{} {} {}% {}/{}
             {}",
             "⟳".cyan().bold(),
@@ -40,11 +42,11 @@ impl ProgressBar {
         );
 
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     pub fn finish(&self) {
-        println!(
+        eprintln!(
             "\r{} {} 100% {}/{}
             {}
             {}",
@@ -80,14 +82,14 @@ impl InfiniteLoader {
         if let Some(progress) = self.progress {
             // If progress is set, show percentage
             if progress >= 100.0 {
-                print!(
+                eprint!(
                     "\r{} {} {}",
                     "✓".green().bold(),
                     self.message.bright_green(),
                     "100%".bright_green().bold()
                 );
             } else {
-                print!(
+                eprint!(
                     "\r{} {} {}",
                     self.frames[self.current_frame].cyan().bold(),
                     self.message.cyan(),
@@ -96,7 +98,7 @@ impl InfiniteLoader {
             }
         } else {
             // No progress, just show spinner
-            print!(
+            eprint!(
                 "\r{} {}",
                 self.frames[self.current_frame].cyan().bold(),
                 self.message.cyan()
@@ -104,22 +106,23 @@ impl InfiniteLoader {
         }
 
         use std::io::{self, Write};
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
         self.current_frame = (self.current_frame + 1) % self.frames.len();
     }
 
     // Set progress percentage (0-100)
     pub fn set_progress(&mut self, percent: f32) {
-        self.progress = Some(percent.min(100.0).max(0.0));
+        self.progress = Some(percent.clamp(0.0, 100.0));
     }
 
     // Clear progress back to infinite
+    #[allow(dead_code)]
     pub fn clear_progress(&mut self) {
         self.progress = None;
     }
 
     pub fn finish(&self, msg: &str) {
-        println!("\r{} {}", "✓".green().bold(), msg.bright_green());
+        eprintln!("\r{} {}", "✓".green().bold(), msg.bright_green());
     }
 
     pub fn update_message(&mut self, msg: &str) {